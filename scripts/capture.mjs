@@ -0,0 +1,72 @@
+// Print a src/tests/captures.json entry skeleton for real mainnet data:
+//
+//   node scripts/capture.mjs tx <signature> <instruction index>
+//   node scripts/capture.mjs account <address>
+//
+// Fill `expected` from an explorer's decoded view of the same instruction or
+// account (not from this decoder), so the entry checks the parsers against
+// the chain rather than against themselves. RPC_URL overrides the endpoint.
+const RPC_URL = process.env.RPC_URL ?? "https://api.mainnet-beta.solana.com";
+const ALPHABET = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+async function rpc(method, params) {
+  const res = await fetch(RPC_URL, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify({ jsonrpc: "2.0", id: 1, method, params }),
+  });
+  const { result, error } = await res.json();
+  if (error) throw new Error(`${method}: ${error.message}`);
+  if (result == null) throw new Error(`${method}: not found`);
+  return result;
+}
+
+function base58ToHex(s) {
+  let n = 0n;
+  for (const c of s) {
+    const d = ALPHABET.indexOf(c);
+    if (d < 0) throw new Error(`invalid base58 character ${c}`);
+    n = n * 58n + BigInt(d);
+  }
+  let hex = n === 0n ? "" : n.toString(16);
+  if (hex.length % 2) hex = "0" + hex;
+  const zeros = s.match(/^1*/)[0].length;
+  return "00".repeat(zeros) + hex;
+}
+
+const [kind, id, index] = process.argv.slice(2);
+let entry;
+if (kind === "tx") {
+  const tx = await rpc("getTransaction", [
+    id,
+    { encoding: "json", maxSupportedTransactionVersion: 0 },
+  ]);
+  const { accountKeys, instructions } = tx.transaction.message;
+  const keys = [
+    ...accountKeys,
+    ...(tx.meta.loadedAddresses?.writable ?? []),
+    ...(tx.meta.loadedAddresses?.readonly ?? []),
+  ];
+  const ix = instructions[Number(index)];
+  if (!ix) throw new Error(`no instruction ${index} in ${id}`);
+  entry = {
+    source: id,
+    programId: keys[ix.programIdIndex],
+    dataHex: base58ToHex(ix.data),
+    accounts: ix.accounts.map((i) => keys[i]),
+    expected: {},
+  };
+} else if (kind === "account") {
+  const { value } = await rpc("getAccountInfo", [id, { encoding: "base64" }]);
+  entry = {
+    source: id,
+    programId: value.owner,
+    dataHex: Buffer.from(value.data[0], "base64").toString("hex"),
+    accounts: [],
+    expected: {},
+  };
+} else {
+  console.error("usage: capture.mjs tx <signature> <index> | account <address>");
+  process.exit(1);
+}
+console.log(JSON.stringify(entry, null, 2));
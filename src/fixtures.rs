@@ -0,0 +1,480 @@
+//! Builders for valid instruction, event and account byte layouts.
+//!
+//! Each builder prepends the discriminator the matching parser expects and
+//! encodes the fields in on-chain (Borsh, little-endian) order, so tests can
+//! construct inputs instead of maintaining hex blobs.
+
+use super::*;
+
+/// Borsh string: u32 LE length followed by the UTF-8 bytes.
+pub fn push_string(out: &mut Vec<u8>, s: &str) {
+    out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+    out.extend_from_slice(s.as_bytes());
+}
+
+/// Pump.fun CreateEvent, as read by `parsePumpFunCreate`.
+pub fn build_pump_fun_create(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    mint: &[u8; 32],
+    bonding_curve: &[u8; 32],
+    developer: &[u8; 32],
+) -> Vec<u8> {
+    let mut out = D_PUMP_CREATE_EVENT.to_vec();
+    push_string(&mut out, name);
+    push_string(&mut out, symbol);
+    push_string(&mut out, uri);
+    out.extend_from_slice(mint);
+    out.extend_from_slice(bonding_curve);
+    out.extend_from_slice(developer);
+    out
+}
+
+/// Pump.fun CompleteEvent, as read by `parsePumpFunCompleteEvent`.
+pub fn build_pump_fun_complete_event(
+    user: &[u8; 32],
+    mint: &[u8; 32],
+    bonding_curve: &[u8; 32],
+    timestamp: i64,
+) -> Vec<u8> {
+    let mut out = D_PUMP_COMPLETE_EVENT.to_vec();
+    out.extend_from_slice(user);
+    out.extend_from_slice(mint);
+    out.extend_from_slice(bonding_curve);
+    out.extend_from_slice(&timestamp.to_le_bytes());
+    out
+}
+
+/// Pump.fun TradeEvent (original fields), as read by `parsePumpFunTradeEvent`.
+pub fn build_pump_fun_trade_event(
+    mint: &[u8; 32],
+    sol_amount: u64,
+    token_amount: u64,
+    is_buy: bool,
+    user: &[u8; 32],
+    timestamp: i64,
+) -> Vec<u8> {
+    let mut out = D_TRADE_EVENT.to_vec();
+    out.extend_from_slice(mint);
+    out.extend_from_slice(&sol_amount.to_le_bytes());
+    out.extend_from_slice(&token_amount.to_le_bytes());
+    out.push(is_buy as u8);
+    out.extend_from_slice(user);
+    out.extend_from_slice(&timestamp.to_le_bytes());
+    out.extend_from_slice(&[0u8; 16]); // virtual sol / token reserves
+    out
+}
+
+/// Pump.fun BondingCurve account, as read by `parsePumpFunCurveState`.
+pub fn build_pump_fun_curve_state(
+    virtual_token_reserves: u64,
+    virtual_sol_reserves: u64,
+    real_token_reserves: u64,
+    real_sol_reserves: u64,
+    token_total_supply: u64,
+    complete: bool,
+) -> Vec<u8> {
+    let mut out = D_ACC_BONDING_CURVE.to_vec();
+    for v in [
+        virtual_token_reserves,
+        virtual_sol_reserves,
+        real_token_reserves,
+        real_sol_reserves,
+        token_total_supply,
+    ] {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out.push(complete as u8);
+    out
+}
+
+/// Pump.fun BondingCurve account in the newer layout with a trailing
+/// `creator`, as read by `parsePumpFunCurveStateWithCreator`.
+pub fn build_pump_fun_curve_state_with_creator(
+    virtual_token_reserves: u64,
+    virtual_sol_reserves: u64,
+    complete: bool,
+    creator: &[u8; 32],
+) -> Vec<u8> {
+    let mut out = build_pump_fun_curve_state(
+        virtual_token_reserves,
+        virtual_sol_reserves,
+        0,
+        0,
+        0,
+        complete,
+    );
+    out.extend_from_slice(creator);
+    out
+}
+
+/// Pump.fun `Global` account in the original layout (no creator fee), as
+/// read by `parsePumpFunGlobal`. Authority and fee recipient are zeroed.
+pub fn build_pump_fun_global(
+    initial_virtual_token_reserves: u64,
+    initial_virtual_sol_reserves: u64,
+    initial_real_token_reserves: u64,
+    token_total_supply: u64,
+    fee_basis_points: u64,
+) -> Vec<u8> {
+    let mut out = D_ACC_PUMP_GLOBAL.to_vec();
+    out.push(1); // initialized
+    out.extend_from_slice(&[0u8; 64]); // authority, fee_recipient
+    for v in [
+        initial_virtual_token_reserves,
+        initial_virtual_sol_reserves,
+        initial_real_token_reserves,
+        token_total_supply,
+        fee_basis_points,
+    ] {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out
+}
+
+/// Boop `create_token`, as read by `parseBoopCreateToken`.
+pub fn build_boop_create_token(salt: u64, name: &str, symbol: &str, uri: &str) -> Vec<u8> {
+    let mut out = D_BOOP_CREATE_TOKEN.to_vec();
+    out.extend_from_slice(&salt.to_le_bytes());
+    push_string(&mut out, name);
+    push_string(&mut out, symbol);
+    push_string(&mut out, uri);
+    out
+}
+
+/// Boop `buy_token` / `sell_token`, as read by `parseBoopBuy` / `parseBoopSell`.
+pub fn build_boop_trade(sell: bool, amount_in: u64, amount_out_min: u64) -> Vec<u8> {
+    let disc = if sell {
+        D_BOOP_SELL_TOKEN
+    } else {
+        D_BOOP_BUY_TOKEN
+    };
+    let mut out = disc.to_vec();
+    out.extend_from_slice(&amount_in.to_le_bytes());
+    out.extend_from_slice(&amount_out_min.to_le_bytes());
+    out
+}
+
+/// Moonshot `token_mint` with the name/symbol/uri prefix of `TokenMintParams`.
+pub fn build_moonshot_token_mint(name: &str, symbol: &str, uri: &str) -> Vec<u8> {
+    let mut out = D_MOONSHOT_TOKEN_MINT.to_vec();
+    push_string(&mut out, name);
+    push_string(&mut out, symbol);
+    push_string(&mut out, uri);
+    out
+}
+
+/// Moonshot `buy` / `sell` `TradeParams`, as read by `parseMoonshotBuy` / `parseMoonshotSell`.
+pub fn build_moonshot_trade(
+    sell: bool,
+    token_amount: u64,
+    collateral_amount: u64,
+    fixed_side: u8,
+    slippage_bps: u64,
+) -> Vec<u8> {
+    let mut out = if sell { D_SELL } else { D_BUY }.to_vec();
+    out.extend_from_slice(&token_amount.to_le_bytes());
+    out.extend_from_slice(&collateral_amount.to_le_bytes());
+    out.push(fixed_side);
+    out.extend_from_slice(&slippage_bps.to_le_bytes());
+    out
+}
+
+/// Raydium Launchpad `buy_exact_in` / `sell_exact_in`.
+pub fn build_launchpad_trade(
+    sell: bool,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    share_fee_rate: u64,
+) -> Vec<u8> {
+    let disc = if sell {
+        D_RAY_SELL_EXACT_IN
+    } else {
+        D_RAY_BUY_EXACT_IN
+    };
+    let mut out = disc.to_vec();
+    out.extend_from_slice(&amount_in.to_le_bytes());
+    out.extend_from_slice(&minimum_amount_out.to_le_bytes());
+    out.extend_from_slice(&share_fee_rate.to_le_bytes());
+    out
+}
+
+/// Meteora DBC `initialize_virtual_pool_with_*`, as read by `parseMeteoraInitialize`.
+pub fn build_meteora_initialize(token2022: bool, name: &str, symbol: &str, uri: &str) -> Vec<u8> {
+    let disc = if token2022 {
+        D_METEORA_INIT_T22
+    } else {
+        D_METEORA_INIT_SPL
+    };
+    let mut out = disc.to_vec();
+    push_string(&mut out, name);
+    push_string(&mut out, symbol);
+    push_string(&mut out, uri);
+    out
+}
+
+/// Raydium Launchpad PlatformConfig account, as read by
+/// `parseLaunchpadPlatformConfig`. Wallets and `cpswap_config` are zeroed.
+pub fn build_launchpad_platform_config(
+    name: &str,
+    fee_rate: u64,
+    creator_fee_rate: u64,
+) -> Vec<u8> {
+    let mut out = D_ACC_PLATFORM_CONFIG.to_vec();
+    out.extend_from_slice(&0u64.to_le_bytes()); // epoch
+    out.extend_from_slice(&[0u8; 64]); // platform_fee_wallet, platform_nft_wallet
+    out.extend_from_slice(&[0u8; 24]); // platform / creator / burn scale
+    out.extend_from_slice(&fee_rate.to_le_bytes());
+    let mut fixed = [0u8; 64];
+    fixed[..name.len()].copy_from_slice(name.as_bytes());
+    out.extend_from_slice(&fixed);
+    out.extend_from_slice(&[0u8; 512]); // web, img
+    out.extend_from_slice(&[0u8; 32]); // cpswap_config
+    out.extend_from_slice(&creator_fee_rate.to_le_bytes());
+    out
+}
+
+/// Jupiter v6 `route` with an empty route plan, as read by `parseJupiterRoute`.
+pub fn build_jupiter_route(
+    in_amount: u64,
+    quoted_out_amount: u64,
+    slippage_bps: u16,
+    platform_fee_bps: u8,
+) -> Vec<u8> {
+    let mut out = D_JUP_ROUTE.to_vec();
+    out.extend_from_slice(&0u32.to_le_bytes()); // route_plan: empty vec
+    out.extend_from_slice(&in_amount.to_le_bytes());
+    out.extend_from_slice(&quoted_out_amount.to_le_bytes());
+    out.extend_from_slice(&slippage_bps.to_le_bytes());
+    out.push(platform_fee_bps);
+    out
+}
+
+/// Token `TransferChecked`, or Token-2022 `TransferCheckedWithFee` when `fee`
+/// is set, as read by `parseToken2022TransferChecked`.
+pub fn build_token_transfer_checked(amount: u64, decimals: u8, fee: Option<u64>) -> Vec<u8> {
+    let mut out = match fee {
+        Some(_) => vec![
+            TAG_TOKEN_TRANSFER_FEE_EXTENSION,
+            TAG_TRANSFER_CHECKED_WITH_FEE,
+        ],
+        None => vec![TAG_TOKEN_TRANSFER_CHECKED],
+    };
+    out.extend_from_slice(&amount.to_le_bytes());
+    out.push(decimals);
+    if let Some(fee) = fee {
+        out.extend_from_slice(&fee.to_le_bytes());
+    }
+    out
+}
+
+/// Meteora DAMM v1 `Pool` account up to `pool_type`, as read by
+/// `parseMeteoraDammPoolState`. Keys are filled with `key`.
+pub fn build_meteora_damm_pool(
+    key: &[u8; 32],
+    trade_fee: (u64, u64),
+    protocol_fee: (u64, u64),
+) -> Vec<u8> {
+    let mut out = D_ACC_METEORA_DAMM_POOL.to_vec();
+    for _ in 0..7 {
+        out.extend_from_slice(key); // lp_mint .. b_vault_lp
+    }
+    out.push(0); // a_vault_lp_bump
+    out.push(1); // enabled
+    out.extend_from_slice(&[0u8; 32 * 2 + 8 + 24]);
+    for v in [trade_fee.0, trade_fee.1, protocol_fee.0, protocol_fee.1] {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out.push(1); // pool_type: Permissionless
+    out
+}
+
+/// Raydium Launchpad `migrate_to_amm`, as read by
+/// `parseRaydiumLaunchpadMigrateToAmm`.
+pub fn build_launchpad_migrate_to_amm(
+    base_lot_size: u64,
+    quote_lot_size: u64,
+    market_vault_signer_nonce: u8,
+) -> Vec<u8> {
+    let mut out = D_RAY_MIGRATE_TO_AMM.to_vec();
+    out.extend_from_slice(&base_lot_size.to_le_bytes());
+    out.extend_from_slice(&quote_lot_size.to_le_bytes());
+    out.push(market_vault_signer_nonce);
+    out
+}
+
+/// Pump.fun `buy`, as read by `parsePumpFunBuyWithFees`.
+pub fn build_pump_fun_buy(amount: u64, max_sol_cost: u64) -> Vec<u8> {
+    let mut out = D_BUY.to_vec();
+    out.extend_from_slice(&amount.to_le_bytes());
+    out.extend_from_slice(&max_sol_cost.to_le_bytes());
+    out
+}
+
+/// Pump.fun `set_creator`, as read by `parsePumpFunAdmin`.
+pub fn build_pump_fun_set_creator(creator: [u8; 32]) -> Vec<u8> {
+    let mut out = D_PUMP_SET_CREATOR.to_vec();
+    out.extend_from_slice(&creator);
+    out
+}
+
+/// Raydium Launchpad `create_vesting_account`, as read by
+/// `parseLaunchpadCreateVesting`.
+pub fn build_launchpad_create_vesting(share_amount: u64) -> Vec<u8> {
+    let mut out = D_RAY_CREATE_VESTING.to_vec();
+    out.extend_from_slice(&share_amount.to_le_bytes());
+    out
+}
+
+/// PumpSwap `create_pool`, as read by `parsePumpSwapCreatePool`. Omits
+/// `coin_creator` when `None`, as in the older args layout.
+pub fn build_pump_swap_create_pool(
+    index: u16,
+    base_amount_in: u64,
+    quote_amount_in: u64,
+    coin_creator: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut out = D_PUMP_SWAP_CREATE_POOL.to_vec();
+    out.extend_from_slice(&index.to_le_bytes());
+    out.extend_from_slice(&base_amount_in.to_le_bytes());
+    out.extend_from_slice(&quote_amount_in.to_le_bytes());
+    if let Some(key) = coin_creator {
+        out.extend_from_slice(&key);
+    }
+    out
+}
+
+/// Raydium CPMM `swap_base_input` (`amount_in`, `minimum_amount_out`) or
+/// `swap_base_output` (`max_amount_in`, `amount_out`).
+pub fn build_cpmm_swap(base_output: bool, amount_in: u64, amount_out: u64) -> Vec<u8> {
+    let mut out = if base_output {
+        D_CPMM_SWAP_BASE_OUTPUT.to_vec()
+    } else {
+        D_CPMM_SWAP_BASE_INPUT.to_vec()
+    };
+    out.extend_from_slice(&amount_in.to_le_bytes());
+    out.extend_from_slice(&amount_out.to_le_bytes());
+    out
+}
+
+/// Pump.fun `create` instruction, as read by `parsePumpFunCreateAndBuy`.
+/// Omits the `creator` arg when `None`, as in the older layout.
+pub fn build_pump_fun_create_ix(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    creator: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut out = D_PUMP_CREATE.to_vec();
+    push_string(&mut out, name);
+    push_string(&mut out, symbol);
+    push_string(&mut out, uri);
+    if let Some(key) = creator {
+        out.extend_from_slice(&key);
+    }
+    out
+}
+
+/// Pump.fun `collect_creator_fee`; discriminator only.
+pub fn build_pump_fun_collect_creator_fee() -> Vec<u8> {
+    D_PUMP_COLLECT_CREATOR_FEE.to_vec()
+}
+
+/// Raydium Launchpad TradeEvent, as read by `parseLaunchpadTradeEvent`.
+/// Reserves other than the real ones after the trade, and all fees, are zero.
+pub fn build_launchpad_trade_event(
+    pool_state: &[u8; 32],
+    amount_in: u64,
+    amount_out: u64,
+    is_buy: bool,
+    real_base_after: u64,
+    real_quote_after: u64,
+) -> Vec<u8> {
+    let mut out = D_TRADE_EVENT.to_vec();
+    out.extend_from_slice(pool_state);
+    out.extend_from_slice(&[0u8; 8 * 5]); // total_base_sell .. real_quote_before
+    out.extend_from_slice(&real_base_after.to_le_bytes());
+    out.extend_from_slice(&real_quote_after.to_le_bytes());
+    out.extend_from_slice(&amount_in.to_le_bytes());
+    out.extend_from_slice(&amount_out.to_le_bytes());
+    out.extend_from_slice(&[0u8; 8 * 4]); // protocol / platform / creator / share fees
+    out.push(if is_buy { 0 } else { 1 });
+    out.push(0); // pool_status: Fund
+    out.push(1); // exact_in
+    out
+}
+
+/// Moonshot `token_mint` with the full `TokenMintParams`, including the
+/// `migration_target` read by `parseMoonshotTokenMint`.
+pub fn build_moonshot_token_mint_full(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    amount: u64,
+    migration_target: u8,
+) -> Vec<u8> {
+    let mut out = build_moonshot_token_mint(name, symbol, uri);
+    out.push(9); // decimals
+    out.push(0); // collateral_currency: Sol
+    out.extend_from_slice(&amount.to_le_bytes());
+    out.push(1); // curve_type: ConstantProductV1
+    out.push(migration_target);
+    out
+}
+
+/// Moonshot CurveAccount, as read by `parseMoonshotCurve`.
+pub fn build_moonshot_curve(
+    mint: &[u8; 32],
+    total_supply: u64,
+    curve_amount: u64,
+    marketcap_threshold: u64,
+    migration_target: u8,
+) -> Vec<u8> {
+    let mut out = D_ACC_MOONSHOT_CURVE.to_vec();
+    out.extend_from_slice(&total_supply.to_le_bytes());
+    out.extend_from_slice(&curve_amount.to_le_bytes());
+    out.extend_from_slice(mint);
+    out.extend_from_slice(&[9, 0, 1]); // decimals, collateral_currency, curve_type
+    out.extend_from_slice(&marketcap_threshold.to_le_bytes());
+    out.push(0); // marketcap_currency: Sol
+    out.extend_from_slice(&0u64.to_le_bytes()); // migration_fee
+    out.extend_from_slice(&0u32.to_le_bytes()); // coef_b
+    out.push(255); // bump
+    out.push(migration_target);
+    out
+}
+
+/// PumpSwap `deposit` / `withdraw`: LP amount then the base and quote bounds.
+pub fn build_pump_swap_liquidity(
+    withdraw: bool,
+    lp_token_amount: u64,
+    base_limit: u64,
+    quote_limit: u64,
+) -> Vec<u8> {
+    let disc = if withdraw {
+        D_PUMP_SWAP_WITHDRAW
+    } else {
+        D_PUMP_SWAP_DEPOSIT
+    };
+    let mut out = disc.to_vec();
+    out.extend_from_slice(&lp_token_amount.to_le_bytes());
+    out.extend_from_slice(&base_limit.to_le_bytes());
+    out.extend_from_slice(&quote_limit.to_le_bytes());
+    out
+}
+
+/// Meteora DAMM v1 `Swap` event, as read by `parseMeteoraDammSwapEvent`.
+pub fn build_meteora_damm_swap_event(
+    in_amount: u64,
+    out_amount: u64,
+    trade_fee: u64,
+    protocol_fee: u64,
+    host_fee: u64,
+) -> Vec<u8> {
+    let mut out = D_METEORA_DAMM_SWAP_EVENT.to_vec();
+    for v in [in_amount, out_amount, trade_fee, protocol_fee, host_fee] {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out
+}
@@ -1,38 +1,194 @@
-use borsh::BorshDeserialize;
-use bs58::encode as bs58_encode;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bs58::{decode as bs58_decode, encode as bs58_encode};
 use js_sys::Array;
-use js_sys::{BigInt, Object, Reflect};
+use js_sys::{BigInt, Object, Reflect, Uint8Array, JSON};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_wasm_bindgen::to_value;
+use serde_wasm_bindgen::{from_value, to_value, Serializer};
+use std::cell::{Cell, OnceCell, RefCell};
 use std::str;
 use wasm_bindgen::prelude::*;
 
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+
 // Console logging macro
 #[macro_export]
 macro_rules! console_log {
     ($($t:tt)*) => (web_sys::console::log_1(&format!($($t)*).into()));
 }
 
+thread_local! {
+    // Whether `payload_checked` compares the discriminator (on by default).
+    static CHECK_DISCRIMINATOR: Cell<bool> = const { Cell::new(true) };
+    // Whether 64-bit integers are returned as decimal strings instead of BigInt.
+    static INT_AS_STRING: Cell<bool> = const { Cell::new(false) };
+    // Default for the per-parser `strict` / `lossy` flags; None keeps each
+    // parser's own default (strict UTF-8, trailing bytes allowed).
+    static STRICT_MODE: Cell<Option<bool>> = const { Cell::new(None) };
+    // Whether pubkeys read from data are returned as bytes instead of Base58.
+    static PUBKEY_AS_BYTES: Cell<bool> = const { Cell::new(false) };
+    // Whether pubkey strings read from data are Base64 instead of Base58.
+    static PUBKEY_BASE64: Cell<bool> = const { Cell::new(false) };
+    // Whether `account_at` checks that keys are 32-byte Base58 (off by default).
+    static VALIDATE_ACCOUNTS: Cell<bool> = const { Cell::new(false) };
+    // Per-platform graduation targets set via `setGraduationThreshold`.
+    static GRADUATION_OVERRIDES: RefCell<Vec<(String, u64)>> = const { RefCell::new(Vec::new()) };
+}
+
 // ---- Functions
 /// Skip the 8-byte discriminator and return the payload or an error.
-fn payload<'a>(data: &'a [u8]) -> Result<&'a [u8], JsValue> {
-    if data.len() < 8 {
-        Err(JsValue::from_str("Data too short"))
+fn payload(data: &[u8]) -> Result<&[u8], JsValue> {
+    payload_n(data, 8)
+}
+
+/// Skip an `n`-byte discriminator or tag (1 for SPL Token, 8 for Anchor).
+fn payload_n(data: &[u8], n: usize) -> Result<&[u8], JsValue> {
+    data.get(n..)
+        .ok_or_else(|| JsValue::from_str("Data too short"))
+}
+
+/// Like `payload`, but also require the discriminator to equal `expected`
+/// unless checking was disabled via `setDiscriminatorCheck(false)`.
+fn payload_checked<'a>(data: &'a [u8], expected: &[u8; 8]) -> Result<&'a [u8], JsValue> {
+    let buf = payload(data)?;
+    if CHECK_DISCRIMINATOR.with(Cell::get) && data[..8] != expected[..] {
+        return Err(JsValue::from_str("discriminator mismatch"));
+    }
+    Ok(buf)
+}
+
+/// Lowercase hex encoding of a byte slice.
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode standard (RFC 4648) base64; trailing `=` padding is optional.
+fn decode_base64(s: &str) -> Result<Vec<u8>, JsValue> {
+    let sextet = |c: u8| -> Result<u32, JsValue> {
+        Ok(match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return Err(JsValue::from_str("Invalid base64 character")),
+        } as u32)
+    };
+
+    let body = s.trim_end_matches('=').as_bytes();
+    if body.len() % 4 == 1 || s.len() - body.len() > 2 {
+        return Err(JsValue::from_str("Invalid base64 length"));
+    }
+    let mut out = Vec::with_capacity(body.len() * 3 / 4);
+    for chunk in body.chunks(4) {
+        let mut acc = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            acc |= sextet(c)? << (18 - 6 * i);
+        }
+        let bytes = acc.to_be_bytes();
+        out.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+    Ok(out)
+}
+
+/// Encode as standard (RFC 4648) base64 with `=` padding.
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let mut buf = [0u8; 3];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let acc = u32::from_be_bytes([0, buf[0], buf[1], buf[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(acc >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Render a raw integer amount with `decimals` places, trimming trailing
+/// zeros: `format_ui_amount(1_500_000_000, 9) == "1.5"`.
+fn format_ui_amount(raw: u64, decimals: u32) -> String {
+    let scale = 10u128.pow(decimals);
+    let (int, frac) = (raw as u128 / scale, raw as u128 % scale);
+    if frac == 0 {
+        return int.to_string();
+    }
+    let frac = format!("{:0width$}", frac, width = decimals as usize);
+    format!("{}.{}", int, frac.trim_end_matches('0'))
+}
+
+/// Convert a 64-bit integer to a JS BigInt, or a decimal string if enabled
+/// via `setBigIntAsString(true)`.
+fn int_value<T>(v: T) -> JsValue
+where
+    T: ToString,
+    BigInt: From<T>,
+{
+    if INT_AS_STRING.with(Cell::get) {
+        JsValue::from_str(&v.to_string())
     } else {
-        Ok(&data[8..])
+        BigInt::from(v).into()
+    }
+}
+
+/// `Reflect::set` that names `key` in the error, so a failed (or refused)
+/// property write says which field it was.
+fn set_field(obj: &JsValue, key: &str, value: &JsValue) -> Result<(), JsValue> {
+    match Reflect::set(obj, &key.into(), value) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(JsValue::from_str(&format!("failed to set field {}", key))),
+        Err(e) => {
+            let detail = e
+                .as_string()
+                .map(|m| format!(": {}", m))
+                .unwrap_or_default();
+            Err(JsValue::from_str(&format!(
+                "failed to set field {}{}",
+                key, detail
+            )))
+        }
     }
 }
 
+/// `off + n` if that range lies within `buf`; `None` on overflow or overrun.
+/// Crafted lengths near `usize::MAX` must not wrap, especially on wasm32.
+fn checked_end(buf: &[u8], off: usize, n: usize) -> Option<usize> {
+    off.checked_add(n).filter(|&end| end <= buf.len())
+}
+
 /// Read a little-endian integer of fixed byte length.
 fn read_le<const N: usize>(buf: &[u8], off: &mut usize) -> Result<[u8; N], JsValue> {
-    if buf.len() < *off + N {
-        Err(JsValue::from_str("Unexpected buffer length"))
-    } else {
-        let mut arr = [0u8; N];
-        arr.copy_from_slice(&buf[*off..*off + N]);
-        *off += N;
-        Ok(arr)
-    }
+    let end = checked_end(buf, *off, N)
+        .ok_or_else(|| JsValue::from_str(&format!("Unexpected buffer length at offset {}", off)))?;
+    let mut arr = [0u8; N];
+    arr.copy_from_slice(&buf[*off..end]);
+    *off = end;
+    Ok(arr)
+}
+
+/// Advance `off` by `n` bytes, failing if that would pass the end of `buf`.
+fn skip(buf: &[u8], off: &mut usize, n: usize) -> Result<(), JsValue> {
+    *off = checked_end(buf, *off, n)
+        .ok_or_else(|| JsValue::from_str(&format!("Skip past end of buffer at offset {}", off)))?;
+    Ok(())
+}
+
+/// Read a single byte.
+fn read_u8(buf: &[u8], off: &mut usize) -> Result<u8, JsValue> {
+    let bytes = read_le::<1>(buf, off)?;
+    Ok(bytes[0])
+}
+
+/// Read a u16 in LE format.
+fn read_u16(buf: &[u8], off: &mut usize) -> Result<u16, JsValue> {
+    let bytes = read_le::<2>(buf, off)?;
+    Ok(u16::from_le_bytes(bytes))
 }
 
 /// Read a u32 in LE format.
@@ -47,29 +203,393 @@ fn read_u64(buf: &[u8], off: &mut usize) -> Result<u64, JsValue> {
     Ok(u64::from_le_bytes(bytes))
 }
 
+/// Read `N` consecutive little-endian u64s (e.g. fixed `[u64; N]` fields).
+fn read_u64_array<const N: usize>(buf: &[u8], off: &mut usize) -> Result<[u64; N], JsValue> {
+    let mut arr = [0u64; N];
+    for v in arr.iter_mut() {
+        *v = read_u64(buf, off)?;
+    }
+    Ok(arr)
+}
+
+/// Read a Solana shortvec (compact-u16) length: 7 bits per byte, at most 3 bytes.
+fn read_compact_u16(buf: &[u8], off: &mut usize) -> Result<u16, JsValue> {
+    let mut value: u32 = 0;
+    for i in 0..3 {
+        let byte = *off
+            .checked_add(i)
+            .and_then(|pos| buf.get(pos))
+            .ok_or_else(|| {
+                JsValue::from_str(&format!("Unexpected end of compact-u16 at offset {}", off))
+            })?;
+        // The third byte may only carry the top 2 bits of a u16.
+        if i == 2 && byte > 0x03 {
+            return Err(JsValue::from_str(&format!(
+                "compact-u16 overflow at offset {}",
+                off
+            )));
+        }
+        value |= ((byte & 0x7f) as u32) << (i * 7);
+        if byte & 0x80 == 0 {
+            // A trailing zero byte means a shorter encoding existed.
+            if i > 0 && byte == 0 {
+                return Err(JsValue::from_str(&format!(
+                    "Non-canonical compact-u16 at offset {}",
+                    off
+                )));
+            }
+            *off += i + 1;
+            return Ok(value as u16);
+        }
+    }
+    Err(JsValue::from_str(&format!(
+        "compact-u16 longer than 3 bytes at offset {}",
+        off
+    )))
+}
+
+/// Read an i64 in LE format (e.g. unix timestamps).
+fn read_i64(buf: &[u8], off: &mut usize) -> Result<i64, JsValue> {
+    let bytes = read_le::<8>(buf, off)?;
+    Ok(i64::from_le_bytes(bytes))
+}
+
 /// Read a length-prefixed UTF-8 string.
 fn read_string(buf: &[u8], off: &mut usize) -> Result<String, JsValue> {
+    let start = *off;
+    let bytes = read_len_prefixed(buf, off)?;
+    let s = str::from_utf8(bytes)
+        .map_err(|_| JsValue::from_str(&format!("Invalid UTF-8 at offset {}", start)))?;
+    Ok(s.to_owned())
+}
+
+/// Read a length-prefixed string, replacing invalid UTF-8 with U+FFFD.
+fn read_string_lossy(buf: &[u8], off: &mut usize) -> Result<String, JsValue> {
+    let bytes = read_len_prefixed(buf, off)?;
+    Ok(String::from_utf8_lossy(bytes).into_owned())
+}
+
+/// Read a fixed-capacity, NUL-padded UTF-8 string (e.g. `[u8; 64]` names).
+fn read_fixed_string(buf: &[u8], off: &mut usize, capacity: usize) -> Result<String, JsValue> {
+    let end = checked_end(buf, *off, capacity).ok_or_else(|| {
+        JsValue::from_str(&format!("Fixed string exceeds buffer at offset {}", off))
+    })?;
+    let raw = &buf[*off..end];
+    let len = raw.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    let s = str::from_utf8(&raw[..len])
+        .map_err(|_| JsValue::from_str(&format!("Invalid UTF-8 at offset {}", off)))?;
+    *off = end;
+    Ok(s.to_owned())
+}
+
+/// Read a u32 length, then return that many bytes.
+fn read_len_prefixed<'a>(buf: &'a [u8], off: &mut usize) -> Result<&'a [u8], JsValue> {
     let len = read_u32(buf, off)? as usize;
-    if buf.len() < *off + len {
-        return Err(JsValue::from_str("String length exceeds buffer"));
+    let end = checked_end(buf, *off, len).ok_or_else(|| {
+        JsValue::from_str(&format!("String length exceeds buffer at offset {}", off))
+    })?;
+    let bytes = &buf[*off..end];
+    *off = end;
+    Ok(bytes)
+}
+
+/// With `strict`, fail unless the reads ended exactly at the end of `buf`.
+/// Catches layout drift that would otherwise leave fields silently unread.
+fn ensure_consumed(buf: &[u8], off: usize, strict: Option<bool>) -> Result<(), JsValue> {
+    if rejects_trailing(strict) && off != buf.len() {
+        return Err(JsValue::from_str(&format!(
+            "{} trailing bytes at offset {}",
+            buf.len().saturating_sub(off),
+            off
+        )));
+    }
+    Ok(())
+}
+
+/// Resolve a parser's `strict` flag: explicit value, else `setStrictMode`.
+fn rejects_trailing(strict: Option<bool>) -> bool {
+    strict.or(STRICT_MODE.with(Cell::get)).unwrap_or(false)
+}
+
+/// Resolve a parser's `lossy` flag: explicit value, else lossy only under
+/// `setStrictMode(false)`.
+fn decodes_lossy(lossy: Option<bool>) -> bool {
+    lossy.unwrap_or_else(|| STRICT_MODE.with(Cell::get) == Some(false))
+}
+
+/// Borsh-decode `T` from the start of `buf`. With `exact`, bytes left over
+/// after `T` are an error. Failures name the offset decoding stopped at.
+fn borsh_decode<T: BorshDeserialize>(buf: &[u8], exact: bool) -> Result<T, JsValue> {
+    let mut rest = buf;
+    let v = <T as BorshDeserialize>::deserialize(&mut rest).map_err(|e| {
+        JsValue::from_str(&format!(
+            "Deserialization failed at offset {}: {}",
+            buf.len() - rest.len(),
+            e
+        ))
+    })?;
+    if exact && !rest.is_empty() {
+        return Err(JsValue::from_str(&format!(
+            "Deserialization failed: {} trailing bytes at offset {}",
+            rest.len(),
+            buf.len() - rest.len()
+        )));
+    }
+    Ok(v)
+}
+
+/// Read the raw 32 bytes of a public key. A short buffer gets its own message
+/// (rather than `read_le`'s generic one) since it usually means a field moved.
+fn read_pubkey_bytes(buf: &[u8], off: &mut usize) -> Result<[u8; 32], JsValue> {
+    if checked_end(buf, *off, 32).is_none() {
+        return Err(JsValue::from_str(&format!(
+            "expected 32-byte pubkey at offset {}, only {} bytes remain",
+            off,
+            buf.len().saturating_sub(*off)
+        )));
+    }
+    read_le::<32>(buf, off)
+}
+
+/// A pubkey as Base58, or Base64 under `setPubkeyEncoding("base64")`.
+fn encode_pubkey(key: [u8; 32]) -> String {
+    if PUBKEY_BASE64.with(Cell::get) {
+        encode_base64(&key)
+    } else {
+        bs58_encode(key).into_string()
     }
-    let s =
-        str::from_utf8(&buf[*off..*off + len]).map_err(|_| JsValue::from_str("Invalid UTF-8"))?;
-    *off += len;
-    Ok(s.to_owned())
 }
 
-/// Read a 32-byte public key and Base58-encode it.
+/// Read a 32-byte public key and encode it (see `encode_pubkey`).
 fn read_pubkey(buf: &[u8], off: &mut usize) -> Result<String, JsValue> {
-    let key = read_le::<32>(buf, off)?;
-    Ok(bs58_encode(key).into_string())
+    read_pubkey_bytes(buf, off).map(encode_pubkey)
+}
+
+/// A pubkey as a string (see `encode_pubkey`), or a `Uint8Array` under
+/// `setPubkeyAsBytes`.
+fn pubkey_value(key: [u8; 32]) -> JsValue {
+    if PUBKEY_AS_BYTES.with(Cell::get) {
+        Uint8Array::from(&key[..]).into()
+    } else {
+        JsValue::from_str(&encode_pubkey(key))
+    }
+}
+
+/// Read a 32-byte public key as a JS value (see `pubkey_value`).
+fn read_pubkey_js(buf: &[u8], off: &mut usize) -> Result<JsValue, JsValue> {
+    read_pubkey_bytes(buf, off).map(pubkey_value)
+}
+
+/// The `fields` subset a caller asked a parser for; `None` means all fields.
+struct FieldFilter(Option<Vec<String>>);
+
+impl FieldFilter {
+    fn wants(&self, key: &str) -> bool {
+        self.0.as_ref().is_none_or(|f| f.iter().any(|k| k == key))
+    }
+
+    /// Set `key` on `obj` if requested, building the value only then.
+    fn set(&self, obj: &Object, key: &str, value: impl FnOnce() -> JsValue) -> Result<(), JsValue> {
+        if self.wants(key) {
+            set_field(obj, key, &value())?;
+        }
+        Ok(())
+    }
+
+    /// Read the pubkey for `key` if requested, else skip its 32 bytes.
+    fn pubkey(&self, key: &str, buf: &[u8], off: &mut usize) -> Result<JsValue, JsValue> {
+        if self.wants(key) {
+            read_pubkey_js(buf, off)
+        } else {
+            skip(buf, off, 32).map(|_| JsValue::NULL)
+        }
+    }
+}
+
+/// JS-facing reader over a copy of `data` for ad-hoc decoding of packed
+/// structs. Each read bounds-checks and advances `offset`; a failed read
+/// leaves the offset unchanged.
+#[wasm_bindgen]
+pub struct Cursor {
+    data: Vec<u8>,
+    off: usize,
+}
+
+#[wasm_bindgen]
+impl Cursor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: &[u8]) -> Cursor {
+        Cursor {
+            data: data.to_vec(),
+            off: 0,
+        }
+    }
+
+    /// Bytes consumed so far.
+    #[wasm_bindgen(getter)]
+    pub fn offset(&self) -> usize {
+        self.off
+    }
+
+    /// Bytes left after `offset`.
+    #[wasm_bindgen(getter)]
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.off
+    }
+
+    /// Run `read` on a scratch offset and commit it only on success.
+    fn read<T>(
+        &mut self,
+        read: impl FnOnce(&[u8], &mut usize) -> Result<T, JsValue>,
+    ) -> Result<T, JsValue> {
+        let mut off = self.off;
+        let v = read(&self.data, &mut off)?;
+        self.off = off;
+        Ok(v)
+    }
+
+    pub fn skip(&mut self, n: usize) -> Result<(), JsValue> {
+        self.read(|buf, off| skip(buf, off, n))
+    }
+
+    #[wasm_bindgen(js_name = "readU8")]
+    pub fn read_u8(&mut self) -> Result<u8, JsValue> {
+        self.read(read_u8)
+    }
+
+    #[wasm_bindgen(js_name = "readBool")]
+    pub fn read_bool(&mut self) -> Result<bool, JsValue> {
+        self.read(read_u8).map(|b| b != 0)
+    }
+
+    #[wasm_bindgen(js_name = "readU16")]
+    pub fn read_u16(&mut self) -> Result<u16, JsValue> {
+        self.read(read_u16)
+    }
+
+    #[wasm_bindgen(js_name = "readU32")]
+    pub fn read_u32(&mut self) -> Result<u32, JsValue> {
+        self.read(read_u32)
+    }
+
+    /// 64-bit reads follow the same BigInt/string setting as the parsers.
+    #[wasm_bindgen(js_name = "readU64")]
+    pub fn read_u64(&mut self) -> Result<JsValue, JsValue> {
+        self.read(read_u64).map(int_value)
+    }
+
+    #[wasm_bindgen(js_name = "readI64")]
+    pub fn read_i64(&mut self) -> Result<JsValue, JsValue> {
+        self.read(read_i64).map(int_value)
+    }
+
+    #[wasm_bindgen(js_name = "readCompactU16")]
+    pub fn read_compact_u16(&mut self) -> Result<u16, JsValue> {
+        self.read(read_compact_u16)
+    }
+
+    /// Borsh string: u32 length, then UTF-8 bytes.
+    #[wasm_bindgen(js_name = "readString")]
+    pub fn read_string(&mut self) -> Result<String, JsValue> {
+        self.read(read_string)
+    }
+
+    /// 32-byte public key, Base58-encoded.
+    #[wasm_bindgen(js_name = "readPubkey")]
+    pub fn read_pubkey(&mut self) -> Result<String, JsValue> {
+        self.read(read_pubkey)
+    }
+
+    /// The next `n` raw bytes.
+    #[wasm_bindgen(js_name = "readBytes")]
+    pub fn read_bytes(&mut self, n: usize) -> Result<Vec<u8>, JsValue> {
+        self.read(|buf, off| {
+            let start = *off;
+            skip(buf, off, n)?;
+            Ok(buf[start..*off].to_vec())
+        })
+    }
+}
+
+/// Accept `accounts` only if it is a JS array (not null/undefined/other).
+fn accounts_array(accounts: &JsValue) -> Result<Array, JsValue> {
+    if !Array::is_array(accounts) {
+        return Err(JsValue::from_str(
+            "accounts must be an array of base58 strings",
+        ));
+    }
+    Ok(Array::from(accounts))
+}
+
+/// Read the `amount` of an SPL token account (mint, owner, then amount).
+fn token_account_amount(data: &[u8]) -> Result<u64, JsValue> {
+    let mut off = 0;
+    skip(data, &mut off, 64)?;
+    read_u64(data, &mut off)
+}
+
+/// Read the Base58 account at index `i`, naming the index on failure.
+fn account_at(accs: &Array, i: u32) -> Result<String, JsValue> {
+    if i >= accs.length() {
+        return Err(JsValue::from_str(&format!(
+            "missing account at index {}",
+            i
+        )));
+    }
+    let key = accs
+        .get(i)
+        .as_string()
+        .ok_or_else(|| JsValue::from_str(&format!("account at index {} is not a string", i)))?;
+    if VALIDATE_ACCOUNTS.with(Cell::get) {
+        decode_pubkey(&key).map_err(|e| {
+            JsValue::from_str(&format!(
+                "account at index {} is not a valid pubkey: {}",
+                i,
+                e.as_string().unwrap_or_default()
+            ))
+        })?;
+    }
+    Ok(key)
 }
 
 // ---- Structs
+/// Normalized output of every create parser; fields a platform's instruction
+/// does not carry are `null`.
 #[derive(Serialize)]
-struct InitializeSimple {
+struct TokenCreate {
+    platform: &'static str,
+    instruction: &'static str,
     name: String,
     symbol: String,
+    uri: Option<String>,
+    mint: Option<String>,
+    pool: Option<String>,
+    developer: Option<String>,
+}
+
+impl TokenCreate {
+    fn new(
+        platform: &'static str,
+        instruction: &'static str,
+        name: String,
+        symbol: String,
+    ) -> Self {
+        TokenCreate {
+            platform,
+            instruction,
+            name,
+            symbol,
+            uri: None,
+            mint: None,
+            pool: None,
+            developer: None,
+        }
+    }
+
+    fn to_js(&self) -> Result<JsValue, JsValue> {
+        self.serialize(&Serializer::new().serialize_missing_as_null(true))
+            .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+    }
 }
 
 #[derive(BorshDeserialize, Debug)]
@@ -80,19 +600,86 @@ pub struct CreateTokenBoopArgs {
     pub uri: String,
 }
 
-/// Metadata struct for Pump.fun / LetsBonk create
+// Boop buy_token / sell_token args (buy_amount / sell_amount, amount_out_min)
+#[derive(BorshDeserialize, Debug)]
+pub struct BoopTradeArgs {
+    pub amount_in: u64,
+    pub amount_out_min: u64,
+}
+
+// Moonshot buy/sell args, matches IDL "TradeParams"
+#[derive(BorshDeserialize, Debug)]
+pub struct MoonshotTradeParams {
+    pub token_amount: u64,
+    pub collateral_amount: u64,
+    /// 0 = ExactIn, 1 = ExactOut
+    pub fixed_side: u8,
+    pub slippage_bps: u64,
+}
+
+// Moonshot config account, matches IDL "ConfigAccount"
+#[derive(BorshDeserialize, Debug)]
+pub struct MoonshotConfigAccount {
+    pub migration_authority: [u8; 32],
+    pub backend_authority: [u8; 32],
+    pub config_authority: [u8; 32],
+    pub helio_fee: [u8; 32],
+    pub dex_fee: [u8; 32],
+    pub fee_bps: u16,
+    pub dex_fee_share: u8,
+    pub migration_fee: u64,
+    pub marketcap_threshold: u64,
+    /// 0 = Sol
+    pub marketcap_currency: u8,
+    pub min_supported_decimal_places: u8,
+    pub max_supported_decimal_places: u8,
+    pub min_supported_token_supply: u64,
+    pub max_supported_token_supply: u64,
+    pub bump: u8,
+    pub coef_b: u32,
+}
+
+// Moonshot curve account, matches IDL "CurveAccount"
+#[derive(BorshDeserialize, Debug)]
+pub struct MoonshotCurveAccount {
+    pub total_supply: u64,
+    pub curve_amount: u64,
+    pub mint: [u8; 32],
+    pub decimals: u8,
+    /// 0 = Sol
+    pub collateral_currency: u8,
+    /// 0 = LinearV1, 1 = ConstantProductV1
+    pub curve_type: u8,
+    pub marketcap_threshold: u64,
+    /// 0 = Sol
+    pub marketcap_currency: u8,
+    pub migration_fee: u64,
+    pub coef_b: u32,
+    pub bump: u8,
+    /// 0 = Raydium, 1 = Meteora
+    pub migration_target: u8,
+}
+
+// Pump.fun set_params args (original IDL layout; later versions append fields)
+#[derive(BorshDeserialize, Debug)]
+pub struct PumpFunSetParamsArgs {
+    pub fee_recipient: [u8; 32],
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub fee_basis_points: u64,
+}
+
+/// Platform-independent display metadata
 #[derive(Serialize)]
-struct ComputedTokenMetaData {
+struct TokenNameSymbol {
     name: String,
     symbol: String,
     uri: String,
-    mint: String,
-    #[serde(rename = "bondingCurve")]
-    bonding_curve: String,
-    developer: String,
 }
 
-#[derive(BorshDeserialize, Serialize)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 pub struct InitializePoolParameters {
     pub name: String,
     pub symbol: String,
@@ -100,7 +687,7 @@ pub struct InitializePoolParameters {
 }
 
 // The three Curve variants
-#[derive(BorshDeserialize, Serialize, Deserialize)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 pub struct ConstantCurve {
     pub supply: u64,
     pub total_base_sell: u64,
@@ -108,32 +695,22 @@ pub struct ConstantCurve {
     pub migrate_type: u8,
 }
 
-#[derive(BorshDeserialize, Serialize, Deserialize)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 pub struct FixedCurve {
     pub supply: u64,
     pub total_quote_fund_raising: u64,
     pub migrate_type: u8,
 }
 
-#[derive(BorshDeserialize, Serialize, Deserialize)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 pub struct LinearCurve {
     pub supply: u64,
     pub total_quote_fund_raising: u64,
     pub migrate_type: u8,
 }
 
-#[derive(Serialize)]
-struct MeteoraInitializeOut {
-    name: String,
-    symbol: String,
-    uri: String,
-    mint: String,          // base_mint (idx 3)
-    bonding_curve: String, // pool / PoolState (idx 5)
-    developer: String,     // creator (idx 2)
-}
-
 // 3) CurveParams enum   matches IDL "CurveParams"
-#[derive(BorshDeserialize, Serialize, Deserialize)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 pub enum CurveParams {
     Constant { data: ConstantCurve },
     Fixed { data: FixedCurve },
@@ -195,300 +772,5941 @@ pub struct InitializeT22 {
     pub transfer_fee_extension_param: Option<TransferFeeExtensionParams>,
 }
 
+// Program ids
+const PROGRAM_PUMP_FUN: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+const PROGRAM_RAYDIUM_LAUNCHPAD: &str = "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj";
+const PROGRAM_MOONSHOT: &str = "MoonCVVNZFSYkqNXP6bxHLPL6QQJiMagDL3qcqUQTrG";
+const PROGRAM_BOOP: &str = "boop8hVGQGqehUK2iVEMEnMrL5RbjywRzHKBmBE7ry4";
+const PROGRAM_METEORA_DBC: &str = "dbcij3LWUppWqq96dh6gJWwBifmcGfLSB5D4DuSMaqN";
+const PROGRAM_JUPITER: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+const PROGRAM_PUMP_SWAP: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
+const PROGRAM_METEORA_DAMM: &str = "Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB";
+const PROGRAM_RAYDIUM_CPMM: &str = "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C";
+const PROGRAM_RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+const PROGRAM_TOKEN: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const PROGRAM_TOKEN_2022: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+const PROGRAM_ATA: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+const PROGRAM_MEMO: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+const PROGRAM_MEMO_V1: &str = "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo";
+// Wrapped SOL, the quote side of most launchpad-graduated pools
+const MINT_WSOL: &str = "So11111111111111111111111111111111111111112";
+
 const D_RAY_INIT: [u8; 8] = [0xaf, 0xaf, 0x6d, 0x1f, 0x0d, 0x98, 0x9b, 0xed];
 const D_RAY_INIT_V2: [u8; 8] = [0x43, 0x99, 0xaf, 0x27, 0xda, 0x10, 0x26, 0x20];
 const D_RAY_INIT_T22: [u8; 8] = [0x25, 0xbe, 0x7e, 0xde, 0x2c, 0x9a, 0xab, 0x11];
+const D_RAY_BUY_EXACT_IN: [u8; 8] = [0xfa, 0xea, 0x0d, 0x7b, 0xd5, 0x9c, 0x13, 0xec];
+const D_RAY_SELL_EXACT_IN: [u8; 8] = [0x95, 0x27, 0xde, 0x9b, 0xd3, 0x7c, 0x98, 0x1a];
+const D_RAY_CLAIM_VESTED: [u8; 8] = [0x31, 0x21, 0x68, 0x1e, 0xbd, 0x9d, 0x4f, 0x23];
+const D_RAY_CREATE_VESTING: [u8; 8] = [0x81, 0xb2, 0x02, 0x0d, 0xd9, 0xac, 0xe6, 0xda];
+// sha256("global:buy") / ("global:sell"), shared by Moonshot and Pump.fun
+const D_BUY: [u8; 8] = [0x66, 0x06, 0x3d, 0x12, 0x01, 0xda, 0xeb, 0xea];
+const D_SELL: [u8; 8] = [0x33, 0xe6, 0x85, 0xa4, 0x01, 0x7f, 0x83, 0xad];
+const D_METEORA_INIT_SPL: [u8; 8] = [0x8c, 0x55, 0xd7, 0xb0, 0x66, 0x36, 0x68, 0x4f];
+const D_METEORA_INIT_T22: [u8; 8] = [0xa9, 0x76, 0x33, 0x4e, 0x91, 0x6e, 0xdc, 0x9b];
+const D_RAY_MIGRATE_TO_AMM: [u8; 8] = [0xcf, 0x52, 0xc0, 0x91, 0xfe, 0xcf, 0x91, 0xdf];
+const D_RAY_MIGRATE_TO_CPSWAP: [u8; 8] = [0x88, 0x5c, 0xc8, 0x67, 0x1c, 0xda, 0x90, 0x8c];
+const D_METEORA_MIGRATE_DAMM: [u8; 8] = [0x1b, 0x01, 0x30, 0x16, 0xb4, 0x3f, 0x76, 0xd9];
+const D_METEORA_MIGRATE_DAMM_V2: [u8; 8] = [0x9c, 0xa9, 0xe6, 0x67, 0x35, 0xe4, 0x50, 0x40];
+const D_BOOP_CREATE_RAYDIUM_POOL: [u8; 8] = [0x41, 0x2d, 0x77, 0x4d, 0xcc, 0xb2, 0x54, 0x02];
+const D_BOOP_CREATE_TOKEN: [u8; 8] = [0x54, 0x34, 0xcc, 0xe4, 0x18, 0x8c, 0xea, 0x4b];
+const D_BOOP_BUY_TOKEN: [u8; 8] = [0x8a, 0x7f, 0x0e, 0x5b, 0x26, 0x57, 0x73, 0x69];
+const D_BOOP_SELL_TOKEN: [u8; 8] = [0x6d, 0x3d, 0x28, 0xbb, 0xe6, 0xb0, 0x87, 0xae];
+const D_MOONSHOT_TOKEN_MINT: [u8; 8] = [0x03, 0x2c, 0xa4, 0xb8, 0x7b, 0x0d, 0xf5, 0xb3];
+const D_MOONSHOT_MIGRATE_FUNDS: [u8; 8] = [0x2a, 0xe5, 0x0a, 0xe7, 0xbd, 0x3e, 0xc1, 0xae];
+// Pump.fun create data is the CreateEvent ("Program data:" log)
+const D_PUMP_CREATE_EVENT: [u8; 8] = [0x1b, 0x72, 0xa9, 0x4d, 0xde, 0xeb, 0x63, 0x76];
+const D_PUMP_CREATE: [u8; 8] = [0x18, 0x1e, 0xc8, 0x28, 0x05, 0x1c, 0x07, 0x77];
+const D_PUMP_MIGRATE: [u8; 8] = [0x9b, 0xea, 0xe7, 0x92, 0xec, 0x9e, 0xa2, 0x1e];
+const D_PUMP_SET_PARAMS: [u8; 8] = [0x1b, 0xea, 0xb2, 0x34, 0x93, 0x02, 0xbb, 0x8d];
+const D_PUMP_EXTEND_ACCOUNT: [u8; 8] = [0xea, 0x66, 0xc2, 0xcb, 0x96, 0x48, 0x3e, 0xe5];
+const D_PUMP_SET_CREATOR: [u8; 8] = [0xfe, 0x94, 0xff, 0x70, 0xcf, 0x8e, 0xaa, 0xa5];
+const D_PUMP_ADMIN_SET_CREATOR: [u8; 8] = [0x45, 0x19, 0xab, 0x8e, 0x39, 0xef, 0x0d, 0x04];
+const D_PUMP_UPDATE_GLOBAL_AUTHORITY: [u8; 8] = [0xe3, 0xb5, 0x4a, 0xc4, 0xd0, 0x15, 0x61, 0xd5];
+const D_PUMP_COLLECT_CREATOR_FEE: [u8; 8] = [0x14, 0x16, 0x56, 0x7b, 0xc6, 0x1c, 0xdb, 0x84];
+const D_PUMP_COMPLETE_EVENT: [u8; 8] = [0x5f, 0x72, 0x61, 0x9c, 0xd4, 0x2e, 0x98, 0x08];
+// sha256("event:TradeEvent")
+const D_TRADE_EVENT: [u8; 8] = [0xbd, 0xdb, 0x7f, 0xd3, 0x4e, 0xe6, 0x61, 0xee];
+const D_JUP_ROUTE: [u8; 8] = [0xe5, 0x17, 0xcb, 0x97, 0x7a, 0xe3, 0xad, 0x2a];
+const D_JUP_SHARED_ACCOUNTS_ROUTE: [u8; 8] = [0xc1, 0x20, 0x9b, 0x33, 0x41, 0xd6, 0x9c, 0x81];
+const D_JUP_EXACT_OUT_ROUTE: [u8; 8] = [0xd0, 0x33, 0xef, 0x97, 0x7b, 0x2b, 0xed, 0x5c];
+const D_CPMM_SWAP_BASE_INPUT: [u8; 8] = [0x8f, 0xbe, 0x5a, 0xda, 0xc4, 0x1e, 0x33, 0xde];
+const D_CPMM_SWAP_BASE_OUTPUT: [u8; 8] = [0x37, 0xd9, 0x62, 0x56, 0xa3, 0x4a, 0xb4, 0xad];
+const D_PUMP_SWAP_CREATE_POOL: [u8; 8] = [0xe9, 0x92, 0xd1, 0x8e, 0xcf, 0x68, 0x40, 0xbc];
+const D_PUMP_SWAP_DEPOSIT: [u8; 8] = [0xf2, 0x23, 0xc6, 0x89, 0x52, 0xe1, 0xf2, 0xb6];
+const D_PUMP_SWAP_WITHDRAW: [u8; 8] = [0xb7, 0x12, 0x46, 0x9c, 0x94, 0x6d, 0xa1, 0x22];
 
-// INFO: Parsers
-/// WASM-exported parser for Boop.create_token
-#[wasm_bindgen(js_name = "parseBoopCreateToken")]
-pub fn parse_boop_create_token(data: &[u8]) -> Result<JsValue, JsValue> {
-    let buf = payload(data)?;
-    let args = CreateTokenBoopArgs::try_from_slice(buf)
-        .map_err(|e| JsValue::from_str(&format!("Deserialization failed: {}", e)))?;
+// Non-Anchor programs use a 1-byte instruction tag
+const TAG_AMM_V4_INITIALIZE2: u8 = 1;
+const TAG_TOKEN_TRANSFER_CHECKED: u8 = 12;
+// Token-2022 TransferFeeExtension, followed by a sub-instruction tag
+const TAG_TOKEN_TRANSFER_FEE_EXTENSION: u8 = 26;
+const TAG_TRANSFER_CHECKED_WITH_FEE: u8 = 1;
+// ATA program; legacy clients send `Create` as empty data
+const TAG_ATA_CREATE: u8 = 0;
+const TAG_ATA_CREATE_IDEMPOTENT: u8 = 1;
 
-    let resp = InitializeSimple {
-        name: args.name,
-        symbol: args.symbol,
-    };
-    to_value(&resp).map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
-}
+// Account discriminators: sha256("account:<Name>")[..8]
+const D_ACC_BONDING_CURVE: [u8; 8] = [0x17, 0xb7, 0xf8, 0x37, 0x60, 0xd8, 0xac, 0x60];
+const D_ACC_POOL_STATE: [u8; 8] = [0xf7, 0xed, 0xe3, 0xf5, 0xd7, 0xc3, 0xde, 0x46];
+const D_ACC_GLOBAL_CONFIG: [u8; 8] = [0x95, 0x08, 0x9c, 0xca, 0xa0, 0xfc, 0xb0, 0xd9];
+const D_ACC_MOONSHOT_CONFIG: [u8; 8] = [0xbd, 0xff, 0x61, 0x46, 0xba, 0xbd, 0x18, 0x66];
+const D_ACC_MOONSHOT_CURVE: [u8; 8] = [0x08, 0x5b, 0x53, 0x1c, 0x84, 0xd8, 0xf8, 0x16];
+const D_ACC_PUMP_SWAP_POOL: [u8; 8] = [0xf1, 0x9a, 0x6d, 0x04, 0x11, 0xb1, 0x6d, 0xbc];
+// Meteora DAMM v1 also names its account "Pool"
+const D_ACC_METEORA_DAMM_POOL: [u8; 8] = D_ACC_PUMP_SWAP_POOL;
+// sha256("event:Swap"), Meteora DAMM v1
+const D_METEORA_DAMM_SWAP_EVENT: [u8; 8] = [0x51, 0x6c, 0xe3, 0xbe, 0xcd, 0xd0, 0x0a, 0xc4];
+const D_ACC_VESTING_RECORD: [u8; 8] = [0x6a, 0xf3, 0xdd, 0xcd, 0xe6, 0x7e, 0x55, 0x53];
+const D_ACC_PLATFORM_CONFIG: [u8; 8] = [0xa0, 0x4e, 0x80, 0x00, 0xf8, 0x53, 0xe6, 0xa0];
+const D_ACC_PUMP_GLOBAL: [u8; 8] = [0xa7, 0xe8, 0xe8, 0xb1, 0xc8, 0x6c, 0x72, 0x7f];
 
-/// WASM-exported parser for Raydium initialize
-#[wasm_bindgen(js_name = "parseRaydiumInitialize")]
-pub fn parse_raydium_initialize(data: &[u8]) -> Result<JsValue, JsValue> {
-    if data.len() < 8 {
-        return Err(JsValue::from_str("short data"));
-    }
-    let (head, buf) = (&data[..8], &data[8..]);
+/// Known discriminators, named "<platform>:<instruction|event|account>".
+const KNOWN_DISCRIMINATORS: &[(&str, [u8; 8])] = &[
+    ("raydium_launchpad:initialize", D_RAY_INIT),
+    ("raydium_launchpad:initialize_v2", D_RAY_INIT_V2),
+    (
+        "raydium_launchpad:initialize_with_token_2022",
+        D_RAY_INIT_T22,
+    ),
+    ("raydium_launchpad:buy_exact_in", D_RAY_BUY_EXACT_IN),
+    ("raydium_launchpad:sell_exact_in", D_RAY_SELL_EXACT_IN),
+    ("raydium_launchpad:claim_vested_token", D_RAY_CLAIM_VESTED),
+    (
+        "raydium_launchpad:create_vesting_account",
+        D_RAY_CREATE_VESTING,
+    ),
+    ("raydium_launchpad:migrate_to_amm", D_RAY_MIGRATE_TO_AMM),
+    (
+        "raydium_launchpad:migrate_to_cpswap",
+        D_RAY_MIGRATE_TO_CPSWAP,
+    ),
+    ("raydium_launchpad:PoolState", D_ACC_POOL_STATE),
+    ("raydium_launchpad:GlobalConfig", D_ACC_GLOBAL_CONFIG),
+    ("raydium_launchpad:VestingRecord", D_ACC_VESTING_RECORD),
+    ("raydium_launchpad:PlatformConfig", D_ACC_PLATFORM_CONFIG),
+    (
+        "meteora_dbc:initialize_virtual_pool_with_spl_token",
+        D_METEORA_INIT_SPL,
+    ),
+    (
+        "meteora_dbc:initialize_virtual_pool_with_token2022",
+        D_METEORA_INIT_T22,
+    ),
+    ("meteora_dbc:migrate_meteora_damm", D_METEORA_MIGRATE_DAMM),
+    ("meteora_dbc:migration_damm_v2", D_METEORA_MIGRATE_DAMM_V2),
+    ("boop:create_raydium_pool", D_BOOP_CREATE_RAYDIUM_POOL),
+    ("boop:create_token", D_BOOP_CREATE_TOKEN),
+    ("boop:buy_token", D_BOOP_BUY_TOKEN),
+    ("boop:sell_token", D_BOOP_SELL_TOKEN),
+    ("moonshot:token_mint", D_MOONSHOT_TOKEN_MINT),
+    ("moonshot:buy", D_BUY),
+    ("moonshot:migrate_funds", D_MOONSHOT_MIGRATE_FUNDS),
+    ("moonshot:sell", D_SELL),
+    ("moonshot:ConfigAccount", D_ACC_MOONSHOT_CONFIG),
+    ("moonshot:CurveAccount", D_ACC_MOONSHOT_CURVE),
+    ("raydium_cpmm:swap_base_input", D_CPMM_SWAP_BASE_INPUT),
+    ("raydium_cpmm:swap_base_output", D_CPMM_SWAP_BASE_OUTPUT),
+    ("pump_swap:create_pool", D_PUMP_SWAP_CREATE_POOL),
+    ("pump_swap:deposit", D_PUMP_SWAP_DEPOSIT),
+    ("pump_swap:withdraw", D_PUMP_SWAP_WITHDRAW),
+    ("pump_swap:Pool", D_ACC_PUMP_SWAP_POOL),
+    ("meteora_damm:Pool", D_ACC_METEORA_DAMM_POOL),
+    ("meteora_damm:Swap", D_METEORA_DAMM_SWAP_EVENT),
+    ("pump_fun:CreateEvent", D_PUMP_CREATE_EVENT),
+    ("pump_fun:create", D_PUMP_CREATE),
+    ("pump_fun:buy", D_BUY),
+    ("pump_fun:migrate", D_PUMP_MIGRATE),
+    ("pump_fun:set_params", D_PUMP_SET_PARAMS),
+    ("pump_fun:extend_account", D_PUMP_EXTEND_ACCOUNT),
+    ("pump_fun:set_creator", D_PUMP_SET_CREATOR),
+    ("pump_fun:admin_set_creator", D_PUMP_ADMIN_SET_CREATOR),
+    (
+        "pump_fun:update_global_authority",
+        D_PUMP_UPDATE_GLOBAL_AUTHORITY,
+    ),
+    ("pump_fun:collect_creator_fee", D_PUMP_COLLECT_CREATOR_FEE),
+    ("pump_fun:CompleteEvent", D_PUMP_COMPLETE_EVENT),
+    ("pump_fun:TradeEvent", D_TRADE_EVENT),
+    ("raydium_launchpad:TradeEvent", D_TRADE_EVENT),
+    ("pump_fun:BondingCurve", D_ACC_BONDING_CURVE),
+    ("pump_fun:Global", D_ACC_PUMP_GLOBAL),
+    ("jupiter:route", D_JUP_ROUTE),
+    ("jupiter:shared_accounts_route", D_JUP_SHARED_ACCOUNTS_ROUTE),
+    ("jupiter:exact_out_route", D_JUP_EXACT_OUT_ROUTE),
+];
 
-    let (name, symbol) = if head == D_RAY_INIT {
-        let v: InitializeV1 = BorshDeserialize::try_from_slice(buf)
-            .map_err(|e| JsValue::from_str(&format!("Deserialization failed: {}", e)))?;
-        (v.base_mint_param.name, v.base_mint_param.symbol)
-    } else if head == D_RAY_INIT_V2 {
-        let v: InitializeV2 = BorshDeserialize::try_from_slice(buf)
-            .map_err(|e| JsValue::from_str(&format!("Deserialization failed: {}", e)))?;
-        (v.base_mint_param.name, v.base_mint_param.symbol)
-    } else if head == D_RAY_INIT_T22 {
-        let v: InitializeT22 = BorshDeserialize::try_from_slice(buf)
-            .map_err(|e| JsValue::from_str(&format!("Deserialization failed: {}", e)))?;
-        (v.base_mint_param.name, v.base_mint_param.symbol)
-    } else {
-        return Err(JsValue::from_str("not a Raydium initialize ix"));
-    };
+/// Default graduation targets in the platform's quote unit (lamports for
+/// Pump.fun: real SOL held when the curve completes, ~85 SOL).
+const GRADUATION_THRESHOLDS: &[(&str, u64)] = &[("pump_fun", 85_000_000_000)];
 
-    to_value(&InitializeSimple { name, symbol })
-        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+/// The graduation target for `platform`: an override if set, else the default.
+fn graduation_threshold(platform: &str) -> Option<u64> {
+    GRADUATION_OVERRIDES
+        .with(|o| {
+            o.borrow()
+                .iter()
+                .find(|(p, _)| p == platform)
+                .map(|(_, t)| *t)
+        })
+        .or_else(|| {
+            GRADUATION_THRESHOLDS
+                .iter()
+                .find(|(p, _)| *p == platform)
+                .map(|(_, t)| *t)
+        })
 }
 
-/// WASM-exported parser for Moonshot `initialize` instruction data
-#[wasm_bindgen(js_name = "parseMoonshotTokenMint")]
-pub fn parse_moonshot_token_mint(data: &[u8]) -> Result<JsValue, JsValue> {
-    // 1. Get the payload (skip the 8-byte discriminator)
-    let buf = payload(data)?;
-
-    // First try the manual parser which is more reliable
-    let mut off = 0;
-    let name = match read_string(buf, &mut off) {
-        Ok(name) => name,
-        Err(_) => return Err(JsValue::from_str("Failed to parse name")),
-    };
+/// Launchpad PoolState `status` while the curve is still raising funds.
+const LAUNCHPAD_STATUS_FUND: u8 = 0;
 
-    let symbol = match read_string(buf, &mut off) {
-        Ok(symbol) => symbol,
-        Err(_) => return Err(JsValue::from_str("Failed to parse symbol")),
-    };
+/// Pump.fun BondingCurve payload: five u64 reserves and the `complete` bool.
+/// The creator appended by newer accounts follows.
+const CURVE_STATE_MIN_LEN: usize = 5 * 8 + 1;
 
-    let token_info = InitializeSimple { name, symbol };
+/// Byte size of each known layout as the parsers read it, discriminator
+/// included; `None` where strings or vecs make the size variable.
+#[rustfmt::skip]
+const LAYOUT_SIZES: &[(&str, Option<usize>)] = &[
+    ("pump_fun:BondingCurve", Some(8 + CURVE_STATE_MIN_LEN)),
+    ("pump_fun:BondingCurveWithCreator", Some(8 + CURVE_STATE_MIN_LEN + 32)),
+    ("pump_fun:Global", Some(8 + 1 + 32 * 2 + 5 * 8)),
+    ("pump_fun:CompleteEvent", Some(8 + 32 * 3 + 8)),
+    ("pump_fun:TradeEvent", Some(8 + 32 + 8 + 8 + 1 + 32 + 8 * 3)),
+    ("raydium_launchpad:TradeEvent", Some(8 + 32 + 13 * 8 + 1 + 1 + 1)),
+    ("pump_fun:CreateEvent", None),
+    ("pump_fun:create", None),
+    ("pump_fun:buy", Some(8 + 8 * 2)),
+    ("pump_fun:set_params", Some(8 + 32 + 5 * 8)),
+    ("pump_fun:extend_account", Some(8)),
+    ("pump_fun:set_creator", Some(8 + 32)),
+    ("pump_fun:admin_set_creator", Some(8 + 32)),
+    ("pump_fun:collect_creator_fee", Some(8)),
+    ("pump_fun:update_global_authority", Some(8)),
+    ("boop:create_token", None),
+    ("boop:buy_token", Some(8 + 8 * 2)),
+    ("boop:sell_token", Some(8 + 8 * 2)),
+    ("moonshot:token_mint", None),
+    ("moonshot:buy", Some(8 + 8 * 2 + 1 + 8)),
+    ("moonshot:sell", Some(8 + 8 * 2 + 1 + 8)),
+    ("moonshot:ConfigAccount", Some(8 + 32 * 5 + 2 + 1 + 8 * 2 + 3 + 8 * 2 + 1 + 4)),
+    ("moonshot:CurveAccount", Some(8 + 8 * 2 + 32 + 3 + 8 + 1 + 8 + 4 + 1 + 1)),
+    ("raydium_launchpad:initialize", None),
+    ("raydium_launchpad:initialize_v2", None),
+    ("raydium_launchpad:initialize_with_token_2022", None),
+    ("raydium_launchpad:buy_exact_in", Some(8 + 8 * 3)),
+    ("raydium_launchpad:sell_exact_in", Some(8 + 8 * 3)),
+    ("raydium_launchpad:migrate_to_amm", Some(8 + 8 * 2 + 1)),
+    ("raydium_launchpad:migrate_to_cpswap", Some(8)),
+    ("raydium_launchpad:create_vesting_account", Some(8 + 8)),
+    ("raydium_launchpad:PoolState", Some(8 + 8 + 5 + 8 * 10 + 8 * 5 + 32 * 7 + 1 + 1 + 62)),
+    ("raydium_launchpad:PlatformConfig", None),
+    ("meteora_dbc:initialize_virtual_pool_with_spl_token", None),
+    ("meteora_dbc:initialize_virtual_pool_with_token2022", None),
+    ("raydium_cpmm:swap_base_input", Some(8 + 8 * 2)),
+    ("raydium_cpmm:swap_base_output", Some(8 + 8 * 2)),
+    ("pump_swap:create_pool", Some(8 + 2 + 8 * 2 + 32)),
+    ("pump_swap:deposit", Some(8 + 8 * 3)),
+    ("pump_swap:withdraw", Some(8 + 8 * 3)),
+    ("pump_swap:Pool", Some(8 + 1 + 2 + 32 * 6 + 8)),
+    ("meteora_damm:Swap", Some(8 + 8 * 5)),
+];
 
-    // Convert to JsValue using to_value
-    to_value(&token_info).map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+/// Static description of an exported parser for `listParsers`.
+struct ParserInfo {
+    name: &'static str,
+    platform: &'static str,
+    kind: &'static str,
+    discriminators: &'static [&'static [u8]],
 }
 
-/// WASM-exported parser for Pump.fun create instruction
-#[wasm_bindgen(js_name = "parsePumpFunCreate")]
-pub fn parse_pump_fun_create(data: &[u8]) -> Result<JsValue, JsValue> {
-    let buf = payload(data)?;
-    let mut off = 0;
+#[rustfmt::skip]
+const PARSERS: &[ParserInfo] = &[
+    ParserInfo { name: "parseTokenNameSymbol", platform: "any", kind: "dispatcher", discriminators: &[] },
+    ParserInfo { name: "decode", platform: "any", kind: "dispatcher", discriminators: &[] },
+    ParserInfo { name: "parseAccount", platform: "any", kind: "dispatcher", discriminators: &[] },
+    ParserInfo { name: "decodeAsJson", platform: "any", kind: "dispatcher", discriminators: &[] },
+    ParserInfo { name: "parseInstructions", platform: "any", kind: "dispatcher", discriminators: &[] },
+    ParserInfo { name: "parseBoopCreateToken", platform: "boop", kind: "instruction", discriminators: &[&D_BOOP_CREATE_TOKEN] },
+    ParserInfo { name: "parseBoopBuy", platform: "boop", kind: "instruction", discriminators: &[&D_BOOP_BUY_TOKEN] },
+    ParserInfo { name: "parseBoopSell", platform: "boop", kind: "instruction", discriminators: &[&D_BOOP_SELL_TOKEN] },
+    ParserInfo { name: "parseRaydiumInitialize", platform: "raydium_launchpad", kind: "instruction", discriminators: &[&D_RAY_INIT, &D_RAY_INIT_V2, &D_RAY_INIT_T22] },
+    ParserInfo { name: "parseRaydiumLaunchpadBuyExactIn", platform: "raydium_launchpad", kind: "instruction", discriminators: &[&D_RAY_BUY_EXACT_IN] },
+    ParserInfo { name: "parseRaydiumLaunchpadSellExactIn", platform: "raydium_launchpad", kind: "instruction", discriminators: &[&D_RAY_SELL_EXACT_IN] },
+    ParserInfo { name: "parseLaunchpadTradeEvent", platform: "raydium_launchpad", kind: "event", discriminators: &[&D_TRADE_EVENT] },
+    ParserInfo { name: "parseLaunchpadPoolState", platform: "raydium_launchpad", kind: "account", discriminators: &[&D_ACC_POOL_STATE] },
+    ParserInfo { name: "parseLaunchpadGlobalConfig", platform: "raydium_launchpad", kind: "account", discriminators: &[&D_ACC_GLOBAL_CONFIG] },
+    ParserInfo { name: "parseLaunchpadGlobalConfigFull", platform: "raydium_launchpad", kind: "account", discriminators: &[&D_ACC_GLOBAL_CONFIG] },
+    ParserInfo { name: "parseLaunchpadVesting", platform: "raydium_launchpad", kind: "account", discriminators: &[&D_ACC_VESTING_RECORD] },
+    ParserInfo { name: "parseLaunchpadClaimVested", platform: "raydium_launchpad", kind: "instruction", discriminators: &[&D_RAY_CLAIM_VESTED] },
+    ParserInfo { name: "parseLaunchpadCreateVesting", platform: "raydium_launchpad", kind: "instruction", discriminators: &[&D_RAY_CREATE_VESTING] },
+    ParserInfo { name: "parseRaydiumLaunchpadMigrateToAmm", platform: "raydium_launchpad", kind: "instruction", discriminators: &[&D_RAY_MIGRATE_TO_AMM] },
+    ParserInfo { name: "parseRaydiumLaunchpadMigrateToCpmm", platform: "raydium_launchpad", kind: "instruction", discriminators: &[&D_RAY_MIGRATE_TO_CPSWAP] },
+    ParserInfo { name: "parseLaunchpadPlatformConfig", platform: "raydium_launchpad", kind: "account", discriminators: &[&D_ACC_PLATFORM_CONFIG] },
+    ParserInfo { name: "parseBelieveCreate", platform: "believe", kind: "instruction", discriminators: &[&D_RAY_INIT, &D_RAY_INIT_V2, &D_RAY_INIT_T22] },
+    ParserInfo { name: "parseMoonshotTokenMint", platform: "moonshot", kind: "instruction", discriminators: &[&D_MOONSHOT_TOKEN_MINT] },
+    ParserInfo { name: "parseMoonshotBuy", platform: "moonshot", kind: "instruction", discriminators: &[&D_BUY] },
+    ParserInfo { name: "parseMoonshotSell", platform: "moonshot", kind: "instruction", discriminators: &[&D_SELL] },
+    ParserInfo { name: "parseMoonshotMigrate", platform: "moonshot", kind: "instruction", discriminators: &[&D_MOONSHOT_MIGRATE_FUNDS] },
+    ParserInfo { name: "parseMoonshotConfig", platform: "moonshot", kind: "account", discriminators: &[&D_ACC_MOONSHOT_CONFIG] },
+    ParserInfo { name: "parseMoonshotCurve", platform: "moonshot", kind: "account", discriminators: &[&D_ACC_MOONSHOT_CURVE] },
+    ParserInfo { name: "parsePumpFunCreate", platform: "pump_fun", kind: "event", discriminators: &[&D_PUMP_CREATE_EVENT] },
+    ParserInfo { name: "parsePumpFunCreateFull", platform: "pump_fun", kind: "event", discriminators: &[&D_PUMP_CREATE_EVENT] },
+    ParserInfo { name: "parsePumpFunCompleteEvent", platform: "pump_fun", kind: "event", discriminators: &[&D_PUMP_COMPLETE_EVENT] },
+    ParserInfo { name: "parsePumpFunTradeEvent", platform: "pump_fun", kind: "event", discriminators: &[&D_TRADE_EVENT] },
+    ParserInfo { name: "parsePumpFunCreateAndBuy", platform: "pump_fun", kind: "instruction", discriminators: &[&D_PUMP_CREATE] },
+    ParserInfo { name: "parsePumpFunBuyWithFees", platform: "pump_fun", kind: "instruction", discriminators: &[&D_BUY] },
+    ParserInfo { name: "parsePumpFunSetParams", platform: "pump_fun", kind: "instruction", discriminators: &[&D_PUMP_SET_PARAMS] },
+    ParserInfo { name: "parsePumpFunCollectCreatorFee", platform: "pump_fun", kind: "instruction", discriminators: &[&D_PUMP_COLLECT_CREATOR_FEE] },
+    ParserInfo { name: "parsePumpFunAdmin", platform: "pump_fun", kind: "instruction", discriminators: &[&D_PUMP_EXTEND_ACCOUNT, &D_PUMP_SET_CREATOR, &D_PUMP_ADMIN_SET_CREATOR, &D_PUMP_UPDATE_GLOBAL_AUTHORITY] },
+    ParserInfo { name: "parsePumpFunCurveState", platform: "pump_fun", kind: "account", discriminators: &[&D_ACC_BONDING_CURVE] },
+    ParserInfo { name: "parsePumpFunCurveStateWithCreator", platform: "pump_fun", kind: "account", discriminators: &[&D_ACC_BONDING_CURVE] },
+    ParserInfo { name: "parsePumpFunCreatorInfo", platform: "pump_fun", kind: "account", discriminators: &[&D_ACC_BONDING_CURVE] },
+    ParserInfo { name: "parsePumpFunGlobal", platform: "pump_fun", kind: "account", discriminators: &[&D_ACC_PUMP_GLOBAL] },
+    ParserInfo { name: "parsePumpSwapCreatePool", platform: "pump_swap", kind: "instruction", discriminators: &[&D_PUMP_SWAP_CREATE_POOL] },
+    ParserInfo { name: "parsePumpSwapDeposit", platform: "pump_swap", kind: "instruction", discriminators: &[&D_PUMP_SWAP_DEPOSIT] },
+    ParserInfo { name: "parsePumpSwapWithdraw", platform: "pump_swap", kind: "instruction", discriminators: &[&D_PUMP_SWAP_WITHDRAW] },
+    ParserInfo { name: "parsePumpSwapPoolState", platform: "pump_swap", kind: "account", discriminators: &[&D_ACC_PUMP_SWAP_POOL] },
+    ParserInfo { name: "parseMeteoraDammPoolState", platform: "meteora_damm", kind: "account", discriminators: &[&D_ACC_METEORA_DAMM_POOL] },
+    ParserInfo { name: "parseMeteoraDammSwapEvent", platform: "meteora_damm", kind: "event", discriminators: &[&D_METEORA_DAMM_SWAP_EVENT] },
+    ParserInfo { name: "parseMeteoraInitialize", platform: "meteora_dbc", kind: "instruction", discriminators: &[&D_METEORA_INIT_SPL, &D_METEORA_INIT_T22] },
+    ParserInfo { name: "parseMeteoraInitializeWithConfig", platform: "meteora_dbc", kind: "instruction", discriminators: &[&D_METEORA_INIT_SPL, &D_METEORA_INIT_T22] },
+    ParserInfo { name: "parseDaosFunCreate", platform: "daos_fun", kind: "instruction", discriminators: &[&D_METEORA_INIT_SPL, &D_METEORA_INIT_T22] },
+    ParserInfo { name: "parseRaydiumCpmmInitialize", platform: "raydium_cpmm", kind: "instruction", discriminators: &[&D_RAY_INIT] },
+    ParserInfo { name: "parseRaydiumCpmmSwapBaseInput", platform: "raydium_cpmm", kind: "instruction", discriminators: &[&D_CPMM_SWAP_BASE_INPUT] },
+    ParserInfo { name: "parseRaydiumCpmmSwapBaseOutput", platform: "raydium_cpmm", kind: "instruction", discriminators: &[&D_CPMM_SWAP_BASE_OUTPUT] },
+    ParserInfo { name: "parseToken2022TransferChecked", platform: "spl_token_2022", kind: "instruction", discriminators: &[&[TAG_TOKEN_TRANSFER_CHECKED], &[TAG_TOKEN_TRANSFER_FEE_EXTENSION, TAG_TRANSFER_CHECKED_WITH_FEE]] },
+    ParserInfo { name: "parseJupiterRoute", platform: "jupiter", kind: "instruction", discriminators: &[&D_JUP_ROUTE, &D_JUP_SHARED_ACCOUNTS_ROUTE, &D_JUP_EXACT_OUT_ROUTE] },
+    ParserInfo { name: "parseSplMemo", platform: "spl_memo", kind: "instruction", discriminators: &[] },
+    ParserInfo { name: "parseCreateAssociatedTokenAccount", platform: "spl_ata", kind: "instruction", discriminators: &[&[TAG_ATA_CREATE], &[TAG_ATA_CREATE_IDEMPOTENT]] },
+    ParserInfo { name: "parseRaydiumAmmV4Initialize2", platform: "raydium_amm_v4", kind: "instruction", discriminators: &[&[TAG_AMM_V4_INITIALIZE2]] },
+];
 
-    let name = read_string(buf, &mut off)?;
-    let symbol = read_string(buf, &mut off)?;
-    let uri = read_string(buf, &mut off)?;
-    let mint = read_pubkey(buf, &mut off)?;
-    let bonding_curve = read_pubkey(buf, &mut off)?;
-    let developer = read_pubkey(buf, &mut off)?;
+/// How a UI should render an output field.
+#[derive(Clone, Copy)]
+enum FieldType {
+    Pubkey,
+    Amount,
+    Count,
+    Flag,
+    Percent,
+    Text,
+}
 
-    let meta = ComputedTokenMetaData {
-        name,
-        symbol,
-        uri,
-        mint,
-        bonding_curve,
-        developer,
-    };
-    to_value(&meta).map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+impl FieldType {
+    fn as_str(self) -> &'static str {
+        match self {
+            FieldType::Pubkey => "pubkey",
+            FieldType::Amount => "amount",
+            FieldType::Count => "count",
+            FieldType::Flag => "flag",
+            FieldType::Percent => "percent",
+            FieldType::Text => "text",
+        }
+    }
 }
 
-/// WASM-exported parser for Pump.fun-style curve state using JS BigInt
-#[wasm_bindgen(js_name = "parsePumpFunCurveState")]
-pub fn parse_pump_fun_curve_state(data: &[u8]) -> Result<JsValue, JsValue> {
-    let buf = payload(data)?;
-    let mut off = 0;
+type Schema = &'static [(&'static str, FieldType)];
 
-    // Read Pump.fun u64 reserves in original order
-    let virtual_token_reserves = read_u64(buf, &mut off)?;
-    let virtual_sol_reserves = read_u64(buf, &mut off)?;
-    let real_token_reserves = read_u64(buf, &mut off)?;
-    let real_sol_reserves = read_u64(buf, &mut off)?;
-    let token_total_supply = read_u64(buf, &mut off)?;
+const CURVE_STATE_SCHEMA: Schema = &[
+    ("virtual_token_reserves", FieldType::Amount),
+    ("virtual_sol_reserves", FieldType::Amount),
+    ("real_token_reserves", FieldType::Amount),
+    ("real_sol_reserves", FieldType::Amount),
+    ("token_total_supply", FieldType::Amount),
+    ("complete", FieldType::Flag),
+    ("progressPercent", FieldType::Percent),
+    ("creator", FieldType::Pubkey),
+    ("remainderHex", FieldType::Text),
+];
 
-    // Read completion flag (bool)
-    if buf.len() < off + 1 {
-        return Err(JsValue::from_str("Unexpected end of buffer"));
-    }
-    let complete = buf[off] != 0;
+const LAUNCHPAD_POOL_STATE_SCHEMA: Schema = &[
+    ("epoch", FieldType::Count),
+    ("status", FieldType::Count),
+    ("baseDecimals", FieldType::Count),
+    ("quoteDecimals", FieldType::Count),
+    ("migrateType", FieldType::Count),
+    ("supply", FieldType::Amount),
+    ("totalBaseSell", FieldType::Amount),
+    ("virtualBase", FieldType::Amount),
+    ("virtualQuote", FieldType::Amount),
+    ("realBase", FieldType::Amount),
+    ("realQuote", FieldType::Amount),
+    ("totalQuoteFundRaising", FieldType::Amount),
+    ("globalConfig", FieldType::Pubkey),
+    ("quoteMint", FieldType::Pubkey),
+    ("progressPercent", FieldType::Percent),
+    ("isComplete", FieldType::Flag),
+    ("remainderHex", FieldType::Text),
+];
 
-    // Build JS object with BigInt and boolean
-    let obj = Object::new();
-    Reflect::set(
-        &obj,
-        &"virtual_token_reserves".into(),
-        &BigInt::from(virtual_token_reserves).into(),
-    )?;
-    Reflect::set(
-        &obj,
-        &"virtual_sol_reserves".into(),
-        &BigInt::from(virtual_sol_reserves).into(),
-    )?;
-    Reflect::set(
-        &obj,
-        &"real_token_reserves".into(),
-        &BigInt::from(real_token_reserves).into(),
-    )?;
-    Reflect::set(
-        &obj,
-        &"real_sol_reserves".into(),
-        &BigInt::from(real_sol_reserves).into(),
-    )?;
-    Reflect::set(
-        &obj,
-        &"token_total_supply".into(),
-        &BigInt::from(token_total_supply).into(),
-    )?;
-    Reflect::set(&obj, &"complete".into(), &JsValue::from_bool(complete))?;
+// `TokenCreate` plus the extra keys some create parsers add
+const TOKEN_CREATE_SCHEMA: Schema = &[
+    ("platform", FieldType::Text),
+    ("instruction", FieldType::Text),
+    ("name", FieldType::Text),
+    ("symbol", FieldType::Text),
+    ("uri", FieldType::Text),
+    ("mint", FieldType::Pubkey),
+    ("pool", FieldType::Pubkey),
+    ("developer", FieldType::Pubkey),
+    ("config", FieldType::Pubkey),
+    ("quote_mint", FieldType::Pubkey),
+    ("base_vault", FieldType::Pubkey),
+    ("quote_vault", FieldType::Pubkey),
+    ("token_type", FieldType::Text),
+    ("dao_config", FieldType::Pubkey),
+    ("platformConfig", FieldType::Pubkey),
+];
 
-    Ok(JsValue::from(obj))
+/// Parsers with field type metadata for `withSchema`.
+#[rustfmt::skip]
+const SCHEMAS: &[(&[&str], Schema)] = &[
+    (&["parsePumpFunCurveState", "parsePumpFunCurveStateWithCreator"], CURVE_STATE_SCHEMA),
+    (&["parseLaunchpadPoolState"], LAUNCHPAD_POOL_STATE_SCHEMA),
+    (&["parseBoopCreateToken", "parseRaydiumInitialize", "parseMoonshotTokenMint", "parsePumpFunCreate", "parseMeteoraInitialize", "parseMeteoraInitializeWithConfig", "parseDaosFunCreate", "parseBelieveCreate", "parsePumpFunCreateAndBuy"], TOKEN_CREATE_SCHEMA),
+];
+
+#[derive(Serialize)]
+struct ParserEntry {
+    name: &'static str,
+    platform: &'static str,
+    kind: &'static str,
+    discriminators: Vec<String>,
 }
 
-/// WASM-exported parser for Raydium Launchpad PoolState using JS BigInt
-#[wasm_bindgen(js_name = "parseLaunchpadPoolState")]
-pub fn parse_launchpad_pool_state(data: &[u8]) -> Result<JsValue, JsValue> {
-    let buf = payload(data)?; // strips 8-byte Anchor discriminator
-    let mut off = 0;
+/// Enable or disable discriminator validation for all parsers (default: enabled).
+/// Disable it to decode data whose header is unknown or from a forked program.
+#[wasm_bindgen(js_name = "setDiscriminatorCheck")]
+pub fn set_discriminator_check(enabled: bool) {
+    CHECK_DISCRIMINATOR.with(|c| c.set(enabled));
+}
 
-    let epoch = read_u64(buf, &mut off)?;
-    off += 1;
-    let status = buf[off];
-    off += 1;
-    let base_decimals = buf[off];
-    off += 1;
-    let quote_decimals = buf[off];
-    off += 1;
-    let migrate_type = buf[off];
-    off += 1;
+/// Return 64-bit integers as decimal strings instead of BigInt (default: false),
+/// for environments that cannot handle BigInt. Applies process-wide.
+#[wasm_bindgen(js_name = "setBigIntAsString")]
+pub fn set_bigint_as_string(enabled: bool) {
+    INT_AS_STRING.with(|c| c.set(enabled));
+}
 
-    let supply = read_u64(buf, &mut off)?;
-    let total_base_sell = read_u64(buf, &mut off)?;
-    let virtual_base = read_u64(buf, &mut off)?;
-    let virtual_quote = read_u64(buf, &mut off)?;
-    let real_base = read_u64(buf, &mut off)?;
-    let real_quote = read_u64(buf, &mut off)?;
-    let total_quote_fund_raising = read_u64(buf, &mut off)?;
+/// Return pubkeys decoded from instruction, event and account data as 32-byte
+/// `Uint8Array`s instead of Base58 strings (default: false). Keys taken from
+/// the `accounts` argument and the fields of create parsers stay strings.
+/// Applies process-wide.
+#[wasm_bindgen(js_name = "setPubkeyAsBytes")]
+pub fn set_pubkey_as_bytes(enabled: bool) {
+    PUBKEY_AS_BYTES.with(|c| c.set(enabled));
+}
 
-    // skip 3 u64s + 5 u64s = 8 total u64s = 8 * 8 = 64 bytes
-    off += 8 * 8;
+/// Encoding of pubkey strings decoded from instruction, event and account
+/// data: `"base58"` (default) or `"base64"`. Keys taken from the `accounts`
+/// argument are returned as given; `setPubkeyAsBytes` takes precedence.
+/// Applies process-wide.
+#[wasm_bindgen(js_name = "setPubkeyEncoding")]
+pub fn set_pubkey_encoding(encoding: &str) -> Result<(), JsValue> {
+    let base64 = match encoding {
+        "base58" => false,
+        "base64" => true,
+        _ => {
+            return Err(JsValue::from_str(
+                "encoding must be \"base58\" or \"base64\"",
+            ))
+        }
+    };
+    PUBKEY_BASE64.with(|c| c.set(base64));
+    Ok(())
+}
 
-    let global_config = read_pubkey(buf, &mut off)?;
-    // skip platform_config and base_mint (2 pubkeys)
-    off += 32 * 2;
-    let quote_mint = read_pubkey(buf, &mut off)?;
+/// Check that every account a parser resolves is a 32-byte Base58 key
+/// (default: disabled). This catches e.g. signatures passed as accounts, at
+/// the cost of a decode per key. Applies process-wide.
+#[wasm_bindgen(js_name = "setAccountValidation")]
+pub fn set_account_validation(enabled: bool) {
+    VALIDATE_ACCOUNTS.with(|c| c.set(enabled));
+}
+
+/// Set the default for every parser's `strict` / `lossy` flags. Applies
+/// process-wide. `true` rejects trailing bytes and invalid UTF-8; `false`
+/// allows trailing bytes and replaces invalid UTF-8 with U+FFFD. Pass
+/// `undefined` to restore each parser's own default. A flag passed to an
+/// individual call still takes precedence.
+#[wasm_bindgen(js_name = "setStrictMode")]
+pub fn set_strict_mode(strict: Option<bool>) {
+    STRICT_MODE.with(|c| c.set(strict));
+}
+
+/// Override the graduation target used by progress computations for
+/// `platform` (e.g. "pump_fun"), in the platform's quote unit (lamports for
+/// Pump.fun). Applies process-wide; targets change as platforms retune.
+#[wasm_bindgen(js_name = "setGraduationThreshold")]
+pub fn set_graduation_threshold(platform: &str, target: u64) -> Result<(), JsValue> {
+    if target == 0 {
+        return Err(JsValue::from_str("Graduation threshold must be non-zero"));
+    }
+    GRADUATION_OVERRIDES.with(|o| {
+        let mut o = o.borrow_mut();
+        match o.iter_mut().find(|(p, _)| p == platform) {
+            Some(entry) => entry.1 = target,
+            None => o.push((platform.to_owned(), target)),
+        }
+    });
+    Ok(())
+}
+
+// INFO: Diagnostics
+/// Crate version of this WASM build, e.g. "0.1.0".
+#[wasm_bindgen(js_name = "version")]
+pub fn version() -> String {
+    env!("CARGO_PKG_VERSION").to_owned()
+}
+
+/// Install `console_error_panic_hook` so Rust panics show a readable message
+/// and stack trace in the console. Call once at startup; repeated calls are no-ops.
+#[wasm_bindgen(js_name = "init")]
+pub fn init() {
+    console_error_panic_hook::set_once();
+}
+
+/// Return the first 8 bytes of `data` as a lowercase hex string.
+#[wasm_bindgen(js_name = "discriminatorHex")]
+pub fn discriminator_hex(data: &[u8]) -> Result<String, JsValue> {
+    if data.len() < 8 {
+        return Err(JsValue::from_str("Data too short"));
+    }
+    Ok(to_hex(&data[..8]))
+}
+
+/// Return the first 8 bytes of `data` as a `Uint8Array`, for lookup maps
+/// keyed on raw bytes.
+#[wasm_bindgen(js_name = "discriminator")]
+pub fn discriminator(data: &[u8]) -> Result<Vec<u8>, JsValue> {
+    data.get(..8)
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| JsValue::from_str("Data too short"))
+}
+
+/// Name the discriminator of `data` from the known table, if any. Some
+/// discriminators are shared across programs (`global:buy` is both Pump.fun's
+/// and Moonshot's), so pass the owning `program_id` to get its
+/// "<platform>:<name>"; without it a shared discriminator is named by its
+/// bare instruction, e.g. "buy".
+#[wasm_bindgen(js_name = "discriminatorName")]
+pub fn discriminator_name(data: &[u8], program_id: Option<String>) -> Option<String> {
+    if let Some(program_id) = program_id {
+        let platform = DECODE_ROUTES
+            .iter()
+            .map(|r| (r.program_id, r.platform))
+            .chain(ACCOUNT_ROUTES.iter().map(|r| (r.program_id, r.platform)))
+            .find_map(|(id, platform)| (id == program_id).then_some(platform))?;
+        return known_instruction(platform, data).map(|ix| format!("{}:{}", platform, ix));
+    }
+
+    let head = data.get(..8)?;
+    let mut names = KNOWN_DISCRIMINATORS
+        .iter()
+        .filter(|(_, d)| d[..] == *head)
+        .map(|(name, _)| *name);
+    let first = names.next()?;
+    match names.next() {
+        None => Some(first.to_owned()),
+        Some(_) => first.split_once(':').map(|(_, ix)| ix.to_owned()),
+    }
+}
+
+/// Whether the first 8 bytes of `data` equal one of `known_discriminators`
+/// (an array of 8-byte `Uint8Array`s), i.e. whether the data carries an
+/// Anchor header to strip. Pass null to check against the built-in table.
+#[wasm_bindgen(js_name = "looksLikeAnchor")]
+pub fn looks_like_anchor(data: &[u8], known_discriminators: JsValue) -> Result<bool, JsValue> {
+    let Some(head) = data.get(..8) else {
+        return Ok(false);
+    };
+    if known_discriminators.is_null() || known_discriminators.is_undefined() {
+        return Ok(KNOWN_DISCRIMINATORS.iter().any(|(_, d)| d[..] == *head));
+    }
+    if !Array::is_array(&known_discriminators) {
+        return Err(JsValue::from_str(
+            "knownDiscriminators must be an array of Uint8Array",
+        ));
+    }
+    for entry in Array::from(&known_discriminators).iter() {
+        let d = entry
+            .dyn_into::<Uint8Array>()
+            .map_err(|_| JsValue::from_str("knownDiscriminators must be an array of Uint8Array"))?;
+        if d.length() != 8 {
+            return Err(JsValue::from_str("discriminators must be 8 bytes"));
+        }
+        if d.to_vec() == head {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Wrap the output of parser `parser` as `{ fields: [{ name, type }], values }`
+/// so generic UIs can render pubkeys, amounts and flags without per-parser
+/// logic. `type` is one of "pubkey", "amount", "count", "flag", "percent",
+/// "text";
+/// `fields` lists only keys present in `values`.
+#[wasm_bindgen(js_name = "withSchema")]
+pub fn with_schema(parser: &str, values: JsValue) -> Result<JsValue, JsValue> {
+    let (_, schema) = SCHEMAS
+        .iter()
+        .find(|(names, _)| names.contains(&parser))
+        .ok_or_else(|| JsValue::from_str(&format!("no schema for {}", parser)))?;
+
+    let fields = Array::new();
+    for (name, ty) in schema.iter() {
+        if Reflect::has(&values, &(*name).into())? {
+            let field = Object::new();
+            set_field(&field, "name", &JsValue::from_str(name))?;
+            set_field(&field, "type", &JsValue::from_str(ty.as_str()))?;
+            fields.push(&field);
+        }
+    }
+
+    let obj = Object::new();
+    set_field(&obj, "fields", &fields)?;
+    set_field(&obj, "values", &values)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Instruction (or event/account) name of `data` within `platform`, from the
+/// known-discriminator table.
+fn known_instruction(platform: &str, data: &[u8]) -> Option<&'static str> {
+    let head = data.get(..8)?;
+    KNOWN_DISCRIMINATORS.iter().find_map(|(name, d)| {
+        let (p, ix) = name.split_once(':')?;
+        (p == platform && d[..] == *head).then_some(ix)
+    })
+}
+
+/// Fixed byte size (discriminator included) of a layout named like the
+/// `discriminatorName` table, e.g. "pump_fun:BondingCurve", for rejecting
+/// mismatched buffers before parsing. Accounts may be allocated larger than
+/// their layout. Returns `None` for variable-length or unknown layouts.
+#[wasm_bindgen(js_name = "expectedSize")]
+pub fn expected_size(layout: &str) -> Option<usize> {
+    LAYOUT_SIZES
+        .iter()
+        .find(|(name, _)| *name == layout)
+        .and_then(|(_, size)| *size)
+}
+
+/// Describe every exported parser as `{ name, platform, kind, discriminators }`,
+/// with discriminators as hex strings (empty where not applicable).
+#[wasm_bindgen(js_name = "listParsers")]
+pub fn list_parsers() -> Result<JsValue, JsValue> {
+    let entries: Vec<ParserEntry> = PARSERS
+        .iter()
+        .map(|p| ParserEntry {
+            name: p.name,
+            platform: p.platform,
+            kind: p.kind,
+            discriminators: p.discriminators.iter().map(|d| to_hex(d)).collect(),
+        })
+        .collect();
+    to_value(&entries).map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// Decode the base64 payload of a `Program data: <base64>` log line (Anchor
+/// events), or of `Program return: <program id> <base64>`. Other lines fail.
+#[wasm_bindgen(js_name = "extractProgramData")]
+pub fn extract_program_data(log_line: &str) -> Result<Vec<u8>, JsValue> {
+    let line = log_line.trim();
+    let encoded = if let Some(rest) = line.strip_prefix("Program data: ") {
+        rest
+    } else if let Some(rest) = line.strip_prefix("Program return: ") {
+        rest.split_once(' ')
+            .map(|(_, data)| data)
+            .ok_or_else(|| JsValue::from_str("Program return line has no data"))?
+    } else {
+        return Err(JsValue::from_str("not a Program data log line"));
+    };
+    decode_base64(encoded.trim())
+}
+
+/// Base58-decode a public key, requiring exactly 32 bytes.
+#[wasm_bindgen(js_name = "decodePubkey")]
+pub fn decode_pubkey(s: &str) -> Result<Vec<u8>, JsValue> {
+    let bytes = bs58_decode(s)
+        .into_vec()
+        .map_err(|e| JsValue::from_str(&format!("Invalid Base58: {}", e)))?;
+    if bytes.len() != 32 {
+        return Err(JsValue::from_str(&format!(
+            "pubkey must be 32 bytes (got {})",
+            bytes.len()
+        )));
+    }
+    Ok(bytes)
+}
+
+// INFO: Parsers
+/// WASM-exported `{ name, symbol, uri }` extractor for any supported create
+/// instruction, dispatched by program id and discriminator.
+#[wasm_bindgen(js_name = "parseTokenNameSymbol")]
+pub fn parse_token_name_symbol(program_id: &str, data: &[u8]) -> Result<JsValue, JsValue> {
+    // Manual layouts start with name, symbol, uri as length-prefixed strings
+    let read_strings = |buf: &[u8]| -> Result<TokenNameSymbol, JsValue> {
+        let mut off = 0;
+        Ok(TokenNameSymbol {
+            name: read_string(buf, &mut off)?,
+            symbol: read_string(buf, &mut off)?,
+            uri: read_string(buf, &mut off)?,
+        })
+    };
+
+    let out = match program_id {
+        PROGRAM_PUMP_FUN => {
+            // Both the create instruction and the CreateEvent lead with the strings
+            let buf = payload_checked(data, &D_PUMP_CREATE_EVENT)
+                .or_else(|_| payload_checked(data, &D_PUMP_CREATE))
+                .map_err(|_| JsValue::from_str("not a Pump.fun create"))?;
+            read_strings(buf)?
+        }
+        PROGRAM_MOONSHOT => read_strings(payload_checked(data, &D_MOONSHOT_TOKEN_MINT)?)?,
+        PROGRAM_RAYDIUM_LAUNCHPAD => {
+            let p = decode_raydium_mint_params(data)?;
+            TokenNameSymbol {
+                name: p.name,
+                symbol: p.symbol,
+                uri: p.uri,
+            }
+        }
+        PROGRAM_BOOP => {
+            let buf = payload_checked(data, &D_BOOP_CREATE_TOKEN)?;
+            let args = borsh_decode::<CreateTokenBoopArgs>(buf, true)?;
+            TokenNameSymbol {
+                name: args.name,
+                symbol: args.symbol,
+                uri: args.uri,
+            }
+        }
+        PROGRAM_METEORA_DBC => {
+            let args = decode_meteora_params(data)?;
+            TokenNameSymbol {
+                name: args.name,
+                symbol: args.symbol,
+                uri: args.uri,
+            }
+        }
+        _ => return Err(JsValue::from_str("unsupported program")),
+    };
+
+    to_value(&out).map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// Set the platform-independent trade fields `{ side, amount, limitAmount,
+/// limitKind }` so consumers can check slippage without per-platform logic.
+/// `limitKind` is `"min_out"` when `limitAmount` is the least output accepted
+/// and `"max_in"` when it is the most input spent.
+fn set_trade_limits(
+    obj: &Object,
+    side: &str,
+    amount: u64,
+    limit_amount: u64,
+    limit_kind: &str,
+) -> Result<(), JsValue> {
+    set_field(obj, "side", &JsValue::from_str(side))?;
+    set_field(obj, "amount", &int_value(amount))?;
+    set_field(obj, "limitAmount", &int_value(limit_amount))?;
+    set_field(obj, "limitKind", &JsValue::from_str(limit_kind))?;
+    Ok(())
+}
+
+/// WASM-exported parser for Boop.create_token
+#[wasm_bindgen(js_name = "parseBoopCreateToken")]
+pub fn parse_boop_create_token(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_BOOP_CREATE_TOKEN)?;
+    let args = borsh_decode::<CreateTokenBoopArgs>(buf, true)?;
+
+    let mut out = TokenCreate::new("boop", "create_token", args.name, args.symbol);
+    out.uri = Some(args.uri);
+    out.to_js()
+}
+
+/// Shared decoder for Boop `buy_token` / `sell_token`
+fn parse_boop_trade(
+    data: &[u8],
+    accounts: JsValue,
+    discriminator: &[u8; 8],
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, discriminator)?;
+    let args = borsh_decode::<BoopTradeArgs>(buf, true)?;
+
+    // Indices per IDL (same for buy and sell):
+    // 0 = mint, 1 = bonding_curve, 6 = buyer / seller
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "amountIn", &int_value(args.amount_in))?;
+    set_field(&obj, "minimumAmountOut", &int_value(args.amount_out_min))?;
+    set_field(&obj, "mint", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "bondingCurve", &JsValue::from_str(&get(1)?))?;
+    set_field(&obj, "user", &JsValue::from_str(&get(6)?))?;
+    // Both sides are exact-in: amount_in bounded by amount_out_min
+    let side = if discriminator == &D_BOOP_BUY_TOKEN {
+        "buy"
+    } else {
+        "sell"
+    };
+    set_trade_limits(&obj, side, args.amount_in, args.amount_out_min, "min_out")?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Boop.buy_token
+#[wasm_bindgen(js_name = "parseBoopBuy")]
+pub fn parse_boop_buy(data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    parse_boop_trade(data, accounts, &D_BOOP_BUY_TOKEN)
+}
+
+/// WASM-exported parser for Boop.sell_token
+#[wasm_bindgen(js_name = "parseBoopSell")]
+pub fn parse_boop_sell(data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    parse_boop_trade(data, accounts, &D_BOOP_SELL_TOKEN)
+}
+
+/// Decode the base mint params of any Raydium Launchpad initialize variant.
+fn decode_raydium_mint_params(data: &[u8]) -> Result<MintParams, JsValue> {
+    if data.len() < 8 {
+        return Err(JsValue::from_str("short data"));
+    }
+    let (head, buf) = (&data[..8], &data[8..]);
+
+    if head == D_RAY_INIT {
+        let v: InitializeV1 = borsh_decode(buf, true)?;
+        Ok(v.base_mint_param)
+    } else if head == D_RAY_INIT_V2 {
+        let v: InitializeV2 = borsh_decode(buf, true)?;
+        Ok(v.base_mint_param)
+    } else if head == D_RAY_INIT_T22 {
+        let v: InitializeT22 = borsh_decode(buf, true)?;
+        Ok(v.base_mint_param)
+    } else {
+        Err(JsValue::from_str("not a Raydium initialize ix"))
+    }
+}
+
+/// WASM-exported parser for Raydium initialize
+#[wasm_bindgen(js_name = "parseRaydiumInitialize")]
+pub fn parse_raydium_initialize(data: &[u8]) -> Result<JsValue, JsValue> {
+    let MintParams {
+        name, symbol, uri, ..
+    } = decode_raydium_mint_params(data)?;
+    let instruction = known_instruction("raydium_launchpad", data).unwrap_or("initialize");
+
+    let mut out = TokenCreate::new("raydium_launchpad", instruction, name, symbol);
+    out.uri = Some(uri);
+    out.to_js()
+}
+
+/// WASM-exported parser for Believe launches, which create tokens through
+/// Raydium LaunchLab (`initialize*`) under Believe's own platform config.
+/// Account indices are the same for all initialize variants.
+#[wasm_bindgen(js_name = "parseBelieveCreate")]
+pub fn parse_believe_create(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    let MintParams {
+        name, symbol, uri, ..
+    } = decode_raydium_mint_params(ix_data)?;
+
+    // 1 = creator, 3 = platform_config, 5 = pool_state, 6 = base_mint
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let out = TokenCreate {
+        platform: "believe",
+        instruction: known_instruction("raydium_launchpad", ix_data).unwrap_or("initialize"),
+        name,
+        symbol,
+        uri: Some(uri),
+        mint: Some(get(6)?),
+        pool: Some(get(5)?),
+        developer: Some(get(1)?),
+    }
+    .to_js()?;
+    set_field(&out, "platformConfig", &JsValue::from_str(&get(3)?))?;
+
+    Ok(out)
+}
+
+/// WASM-exported parser for Moonshot `initialize` instruction data.
+/// Pass `lossy = true` to replace invalid UTF-8 in names instead of failing.
+#[wasm_bindgen(js_name = "parseMoonshotTokenMint")]
+pub fn parse_moonshot_token_mint(data: &[u8], lossy: Option<bool>) -> Result<JsValue, JsValue> {
+    // 1. Get the payload (check and skip the 8-byte discriminator)
+    let buf = payload_checked(data, &D_MOONSHOT_TOKEN_MINT)?;
+    let read_string = if decodes_lossy(lossy) {
+        read_string_lossy
+    } else {
+        read_string
+    };
+
+    // First try the manual parser which is more reliable
+    let mut off = 0;
+    let name = match read_string(buf, &mut off) {
+        Ok(name) => name,
+        Err(e) => {
+            return Err(JsValue::from_str(&format!(
+                "Failed to parse name: {}",
+                e.as_string().unwrap_or_default()
+            )))
+        }
+    };
+
+    let symbol = match read_string(buf, &mut off) {
+        Ok(symbol) => symbol,
+        Err(e) => {
+            return Err(JsValue::from_str(&format!(
+                "Failed to parse symbol: {}",
+                e.as_string().unwrap_or_default()
+            )))
+        }
+    };
+
+    let mut out = TokenCreate::new("moonshot", "token_mint", name, symbol);
+    out.uri = read_string(buf, &mut off).ok();
+    let has_uri = out.uri.is_some();
+    let out = out.to_js()?;
+
+    // Rest of TokenMintParams: decimals, collateralCurrency, amount, curveType,
+    // migrationTarget. Only the DEX choice is surfaced, and only when present.
+    if has_uri && checked_end(buf, off, 1 + 1 + 8 + 1 + 1).is_some() {
+        skip(buf, &mut off, 1 + 1 + 8 + 1)?;
+        let target = moonshot_migration_target(read_u8(buf, &mut off)?)?;
+        set_field(&out, "migrationTarget", &JsValue::from_str(target))?;
+    }
+    Ok(out)
+}
+
+/// IDL name of a Moonshot `MigrationTarget`, the DEX a curve graduates to.
+fn moonshot_migration_target(v: u8) -> Result<&'static str, JsValue> {
+    match v {
+        0 => Ok("Raydium"),
+        1 => Ok("Meteora"),
+        _ => Err(JsValue::from_str("Unknown migration target")),
+    }
+}
+
+/// Shared decoder for Moonshot `buy` / `sell` (both take `TradeParams`)
+fn parse_moonshot_trade(data: &[u8], discriminator: &[u8; 8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, discriminator)?;
+    let args = borsh_decode::<MoonshotTradeParams>(buf, true)?;
+
+    let fixed_side = match args.fixed_side {
+        0 => "ExactIn",
+        1 => "ExactOut",
+        _ => return Err(JsValue::from_str("Unknown fixed side")),
+    };
+
+    let obj = Object::new();
+    set_field(&obj, "tokenAmount", &int_value(args.token_amount))?;
+    set_field(&obj, "collateralAmount", &int_value(args.collateral_amount))?;
+    set_field(&obj, "fixedSide", &JsValue::from_str(fixed_side))?;
+    set_field(&obj, "slippageBps", &int_value(args.slippage_bps))?;
+
+    // Moonshot carries no explicit bound: the fixed side is the amount and the
+    // other side is the quote that `slippageBps` is applied to on-chain.
+    let (side, tokens_out) = if discriminator == &D_BUY {
+        ("buy", true)
+    } else {
+        ("sell", false)
+    };
+    let (amount, limit_amount, limit_kind) = match (args.fixed_side, tokens_out) {
+        (0, true) => (args.collateral_amount, args.token_amount, "min_out"),
+        (0, false) => (args.token_amount, args.collateral_amount, "min_out"),
+        (_, true) => (args.token_amount, args.collateral_amount, "max_in"),
+        (_, false) => (args.collateral_amount, args.token_amount, "max_in"),
+    };
+    set_trade_limits(&obj, side, amount, limit_amount, limit_kind)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Moonshot `buy` instruction data
+#[wasm_bindgen(js_name = "parseMoonshotBuy")]
+pub fn parse_moonshot_buy(data: &[u8]) -> Result<JsValue, JsValue> {
+    parse_moonshot_trade(data, &D_BUY)
+}
+
+/// WASM-exported parser for Moonshot `sell` instruction data
+#[wasm_bindgen(js_name = "parseMoonshotSell")]
+pub fn parse_moonshot_sell(data: &[u8]) -> Result<JsValue, JsValue> {
+    parse_moonshot_trade(data, &D_SELL)
+}
+
+/// WASM-exported parser for Moonshot `migrateFunds`, which marks a graduation.
+/// It takes no args and only moves the curve's funds to the migration
+/// authority, which creates the Raydium/Meteora pool in a later transaction,
+/// so no destination pool or amounts are available here.
+#[wasm_bindgen(js_name = "parseMoonshotMigrate")]
+pub fn parse_moonshot_migrate(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    payload_checked(ix_data, &D_MOONSHOT_MIGRATE_FUNDS)?;
+
+    // Indices per IDL:
+    // 1 = migrationAuthority, 2 = curveAccount,
+    // 4 = migrationAuthorityTokenAccount, 5 = mint
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
 
-    // Build JS object with key fields
     let obj = Object::new();
-    Reflect::set(&obj, &"status".into(), &JsValue::from_f64(status as f64))?;
-    Reflect::set(
+    set_field(&obj, "mint", &JsValue::from_str(&get(5)?))?;
+    set_field(&obj, "curveAccount", &JsValue::from_str(&get(2)?))?;
+    set_field(&obj, "migrationAuthority", &JsValue::from_str(&get(1)?))?;
+    set_field(
         &obj,
-        &"virtualBase".into(),
-        &BigInt::from(virtual_base).into(),
+        "migrationAuthorityTokenAccount",
+        &JsValue::from_str(&get(4)?),
     )?;
-    Reflect::set(
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the Moonshot ConfigAccount (fees and curve limits)
+#[wasm_bindgen(js_name = "parseMoonshotConfig")]
+pub fn parse_moonshot_config(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_MOONSHOT_CONFIG)?;
+    // Accounts may carry trailing space, so don't require an exact fit
+    let cfg = borsh_decode::<MoonshotConfigAccount>(buf, false)?;
+    let key = pubkey_value;
+
+    let obj = Object::new();
+    set_field(&obj, "migrationAuthority", &key(cfg.migration_authority))?;
+    set_field(&obj, "backendAuthority", &key(cfg.backend_authority))?;
+    set_field(&obj, "configAuthority", &key(cfg.config_authority))?;
+    set_field(&obj, "helioFee", &key(cfg.helio_fee))?;
+    set_field(&obj, "dexFee", &key(cfg.dex_fee))?;
+    set_field(&obj, "feeBps", &JsValue::from_f64(cfg.fee_bps as f64))?;
+    set_field(
         &obj,
-        &"globalConfig".into(),
-        &JsValue::from_str(&global_config),
+        "dexFeeShare",
+        &JsValue::from_f64(cfg.dex_fee_share as f64),
     )?;
-    Reflect::set(&obj, &"quoteMint".into(), &JsValue::from_str(&quote_mint))?;
-    Reflect::set(
+    set_field(&obj, "migrationFee", &int_value(cfg.migration_fee))?;
+    set_field(
         &obj,
-        &"virtualQuote".into(),
-        &BigInt::from(virtual_quote).into(),
+        "marketcapThreshold",
+        &int_value(cfg.marketcap_threshold),
     )?;
-    Reflect::set(&obj, &"realBase".into(), &BigInt::from(real_base).into())?;
-    Reflect::set(&obj, &"realQuote".into(), &BigInt::from(real_quote).into())?;
-    Reflect::set(&obj, &"supply".into(), &BigInt::from(supply).into())?;
-    Reflect::set(
+    set_field(
         &obj,
-        &"totalBaseSell".into(),
-        &BigInt::from(total_base_sell).into(),
+        "marketcapCurrency",
+        &JsValue::from_f64(cfg.marketcap_currency as f64),
     )?;
-    Reflect::set(
+    set_field(
         &obj,
-        &"totalQuoteFundRaising".into(),
-        &BigInt::from(total_quote_fund_raising).into(),
+        "minSupportedDecimalPlaces",
+        &JsValue::from_f64(cfg.min_supported_decimal_places as f64),
     )?;
-    Reflect::set(
+    set_field(
         &obj,
-        &"baseDecimals".into(),
-        &JsValue::from_f64(base_decimals as f64),
+        "maxSupportedDecimalPlaces",
+        &JsValue::from_f64(cfg.max_supported_decimal_places as f64),
     )?;
-    Reflect::set(
+    set_field(
         &obj,
-        &"quoteDecimals".into(),
-        &JsValue::from_f64(quote_decimals as f64),
+        "minSupportedTokenSupply",
+        &int_value(cfg.min_supported_token_supply),
     )?;
-    Reflect::set(
+    set_field(
         &obj,
-        &"migrateType".into(),
-        &JsValue::from_f64(migrate_type as f64),
+        "maxSupportedTokenSupply",
+        &int_value(cfg.max_supported_token_supply),
     )?;
-    Reflect::set(&obj, &"epoch".into(), &BigInt::from(epoch).into())?;
+    set_field(&obj, "bump", &JsValue::from_f64(cfg.bump as f64))?;
+    set_field(&obj, "coefB", &JsValue::from_f64(cfg.coef_b as f64))?;
 
     Ok(JsValue::from(obj))
 }
 
-#[wasm_bindgen(js_name = "parseLaunchpadGlobalConfig")]
-pub fn parse_launchpad_global_config(data: &[u8]) -> Result<JsValue, JsValue> {
-    let buf = payload(data)?;
-    let mut off = 0;
-
-    // Only read the curve_type field
-    off += 8; // Skip the epoch (u64)
-    let curve_type = buf[off];
+/// WASM-exported parser for a Moonshot CurveAccount. `migrationTarget` is the
+/// DEX the curve migrates to on graduation (`"Raydium"` or `"Meteora"`), and
+/// `marketcapThreshold` is the market cap that triggers it.
+#[wasm_bindgen(js_name = "parseMoonshotCurve")]
+pub fn parse_moonshot_curve(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_MOONSHOT_CURVE)?;
+    // Accounts may carry trailing space, so don't require an exact fit
+    let curve = borsh_decode::<MoonshotCurveAccount>(buf, false)?;
+    let curve_type = match curve.curve_type {
+        0 => "LinearV1",
+        1 => "ConstantProductV1",
+        _ => return Err(JsValue::from_str("Unknown curve type")),
+    };
 
-    // Return curve_type as a JS object
     let obj = Object::new();
-    Reflect::set(
+    set_field(&obj, "mint", &pubkey_value(curve.mint))?;
+    set_field(&obj, "totalSupply", &int_value(curve.total_supply))?;
+    set_field(&obj, "curveAmount", &int_value(curve.curve_amount))?;
+    set_field(&obj, "decimals", &JsValue::from_f64(curve.decimals as f64))?;
+    set_field(
+        &obj,
+        "collateralCurrency",
+        &JsValue::from_f64(curve.collateral_currency as f64),
+    )?;
+    set_field(&obj, "curveType", &JsValue::from_str(curve_type))?;
+    set_field(
         &obj,
-        &"curveType".into(),
-        &JsValue::from_f64(curve_type as f64),
+        "marketcapThreshold",
+        &int_value(curve.marketcap_threshold),
+    )?;
+    set_field(
+        &obj,
+        "marketcapCurrency",
+        &JsValue::from_f64(curve.marketcap_currency as f64),
+    )?;
+    set_field(&obj, "migrationFee", &int_value(curve.migration_fee))?;
+    set_field(&obj, "coefB", &JsValue::from_f64(curve.coef_b as f64))?;
+    set_field(&obj, "bump", &JsValue::from_f64(curve.bump as f64))?;
+    set_field(
+        &obj,
+        "migrationTarget",
+        &JsValue::from_str(moonshot_migration_target(curve.migration_target)?),
     )?;
 
     Ok(JsValue::from(obj))
 }
 
-#[wasm_bindgen(js_name = "parseMeteoraInitialize")]
-pub fn parse_meteora_initialize(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
-    // Try decode assuming discriminator is present (skip 8), then fallback to raw.
-    let try_decode = |buf: &[u8]| -> Result<InitializePoolParameters, JsValue> {
-        // Borsh::deserialize expects &mut &[u8], not a Cursor.
-        let mut slice = buf;
-        InitializePoolParameters::deserialize(&mut slice)
-            .map_err(|e| JsValue::from_str(&format!("Borsh: {}", e)))
+/// WASM-exported parser for the Pump.fun CreateEvent (the create's
+/// `Program data:` log), whose layout follows the create instruction args.
+/// Pass `lossy = true` to replace invalid UTF-8 in strings instead of failing,
+/// and `strict = true` to reject bytes after the developer key.
+#[wasm_bindgen(js_name = "parsePumpFunCreate")]
+pub fn parse_pump_fun_create(
+    data: &[u8],
+    lossy: Option<bool>,
+    strict: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_PUMP_CREATE_EVENT)?;
+    let read_string = if decodes_lossy(lossy) {
+        read_string_lossy
+    } else {
+        read_string
     };
+    let mut off = 0;
 
-    // Attempt A: data includes 8-byte header
-    let args = match payload(ix_data) {
-        Ok(body) => match try_decode(body) {
-            Ok(a) => a,
-            Err(_e1) => {
-                // Attempt B: data already header-less; decode from start
-                try_decode(ix_data)?
-            }
-        },
-        Err(_e0) => {
-            // Too short or not a header; try raw
-            try_decode(ix_data)?
-        }
-    };
+    let name = read_string(buf, &mut off)?;
+    let symbol = read_string(buf, &mut off)?;
+    let uri = read_string(buf, &mut off)?;
+    let mint = read_pubkey(buf, &mut off)?;
+    let bonding_curve = read_pubkey(buf, &mut off)?;
+    let developer = read_pubkey(buf, &mut off)?;
+    ensure_consumed(buf, off, strict)?;
 
-    // 2) Accounts aus Webhook/WS: Array von Base58-Strings
-    let accs = Array::from(&accounts);
-    let get = |i: u32| {
-        accs.get(i)
-            .as_string()
-            .ok_or(JsValue::from_str("bad accounts[i]"))
-    };
+    TokenCreate {
+        platform: "pump_fun",
+        instruction: "CreateEvent",
+        name,
+        symbol,
+        uri: Some(uri),
+        mint: Some(mint),
+        pool: Some(bonding_curve),
+        developer: Some(developer),
+    }
+    .to_js()
+}
 
-    // Indizes lt. IDL:
-    // 2 = creator, 3 = base_mint, 5 = pool (PoolState)
-    let out = MeteoraInitializeOut {
-        name: args.name,
-        symbol: args.symbol,
-        uri: args.uri,
-        developer: get(2)?,
-        mint: get(3)?,
-        bonding_curve: get(5)?,
+/// WASM-exported parser combining a Pump.fun create with the new bonding curve
+/// account, merged into one object. An empty `curve_account_data` returns only
+/// the create fields.
+#[wasm_bindgen(js_name = "parsePumpFunCreateFull")]
+pub fn parse_pump_fun_create_full(
+    ix_data: &[u8],
+    curve_account_data: &[u8],
+) -> Result<JsValue, JsValue> {
+    let meta = parse_pump_fun_create(ix_data, None, None)?;
+    if curve_account_data.is_empty() {
+        return Ok(meta);
+    }
+    let curve = parse_pump_fun_curve_state(curve_account_data, None, None)?;
+
+    let merged = Object::assign(&Object::from(meta), &Object::from(curve));
+    Ok(JsValue::from(merged))
+}
+
+/// WASM-exported parser for the Pump.fun CompleteEvent (bonding curve graduated).
+/// Pass `strict = true` to reject bytes after the timestamp.
+#[wasm_bindgen(js_name = "parsePumpFunCompleteEvent")]
+pub fn parse_pump_fun_complete_event(
+    data: &[u8],
+    strict: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_PUMP_COMPLETE_EVENT)?;
+    let mut off = 0;
+
+    let user = read_pubkey_js(buf, &mut off)?;
+    let mint = read_pubkey_js(buf, &mut off)?;
+    let bonding_curve = read_pubkey_js(buf, &mut off)?;
+    let timestamp = read_i64(buf, &mut off)?;
+    ensure_consumed(buf, off, strict)?;
+
+    let obj = Object::new();
+    set_field(&obj, "user", &user)?;
+    set_field(&obj, "mint", &mint)?;
+    set_field(&obj, "bondingCurve", &bonding_curve)?;
+    set_field(&obj, "timestamp", &int_value(timestamp))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the Pump.fun TradeEvent emitted by `buy` / `sell`.
+/// Reads the original fields through the virtual reserves; `solAmountUi` is
+/// `solAmount` in SOL as a decimal string. Pass `fields` to return only
+/// those keys.
+#[wasm_bindgen(js_name = "parsePumpFunTradeEvent")]
+pub fn parse_pump_fun_trade_event(
+    data: &[u8],
+    fields: Option<Vec<String>>,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_TRADE_EVENT)?;
+    let f = FieldFilter(fields);
+    let mut off = 0;
+
+    let mint = f.pubkey("mint", buf, &mut off)?;
+    let sol_amount = read_u64(buf, &mut off)?;
+    let token_amount = read_u64(buf, &mut off)?;
+    let is_buy = read_u8(buf, &mut off)? != 0;
+    let user = f.pubkey("user", buf, &mut off)?;
+    let timestamp = read_i64(buf, &mut off)?;
+    let virtual_sol_reserves = read_u64(buf, &mut off)?;
+    let virtual_token_reserves = read_u64(buf, &mut off)?;
+
+    let obj = Object::new();
+    f.set(&obj, "mint", || mint)?;
+    f.set(&obj, "solAmount", || int_value(sol_amount))?;
+    f.set(&obj, "solAmountUi", || {
+        JsValue::from_str(&format_ui_amount(sol_amount, 9))
+    })?;
+    f.set(&obj, "tokenAmount", || int_value(token_amount))?;
+    f.set(&obj, "isBuy", || JsValue::from_bool(is_buy))?;
+    f.set(&obj, "user", || user)?;
+    f.set(&obj, "timestamp", || int_value(timestamp))?;
+    f.set(&obj, "virtualSolReserves", || {
+        int_value(virtual_sol_reserves)
+    })?;
+    f.set(&obj, "virtualTokenReserves", || {
+        int_value(virtual_token_reserves)
+    })?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the Raydium Launchpad (LetsBonk) TradeEvent from
+/// `Program data:` logs. Shares its discriminator with the Pump.fun event, so
+/// pick the parser by the emitting program. `isBuy` comes from
+/// `trade_direction` (0 = buy); `poolStatus` is the post-trade PoolState status.
+#[wasm_bindgen(js_name = "parseLaunchpadTradeEvent")]
+pub fn parse_launchpad_trade_event(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_TRADE_EVENT)?;
+    let mut off = 0;
+
+    let pool_state = read_pubkey_js(buf, &mut off)?;
+    let total_base_sell = read_u64(buf, &mut off)?;
+    let virtual_base = read_u64(buf, &mut off)?;
+    let virtual_quote = read_u64(buf, &mut off)?;
+    let real_base_before = read_u64(buf, &mut off)?;
+    let real_quote_before = read_u64(buf, &mut off)?;
+    let real_base_after = read_u64(buf, &mut off)?;
+    let real_quote_after = read_u64(buf, &mut off)?;
+    let amount_in = read_u64(buf, &mut off)?;
+    let amount_out = read_u64(buf, &mut off)?;
+    let protocol_fee = read_u64(buf, &mut off)?;
+    let platform_fee = read_u64(buf, &mut off)?;
+    let creator_fee = read_u64(buf, &mut off)?;
+    let share_fee = read_u64(buf, &mut off)?;
+    let is_buy = read_u8(buf, &mut off)? == 0;
+    let pool_status = read_u8(buf, &mut off)?;
+    let exact_in = read_u8(buf, &mut off)? != 0;
+
+    let obj = Object::new();
+    set_field(&obj, "poolState", &pool_state)?;
+    set_field(&obj, "isBuy", &JsValue::from_bool(is_buy))?;
+    set_field(&obj, "exactIn", &JsValue::from_bool(exact_in))?;
+    set_field(&obj, "amountIn", &int_value(amount_in))?;
+    set_field(&obj, "amountOut", &int_value(amount_out))?;
+    set_field(&obj, "totalBaseSell", &int_value(total_base_sell))?;
+    set_field(&obj, "virtualBase", &int_value(virtual_base))?;
+    set_field(&obj, "virtualQuote", &int_value(virtual_quote))?;
+    set_field(&obj, "realBaseBefore", &int_value(real_base_before))?;
+    set_field(&obj, "realQuoteBefore", &int_value(real_quote_before))?;
+    set_field(&obj, "realBaseAfter", &int_value(real_base_after))?;
+    set_field(&obj, "realQuoteAfter", &int_value(real_quote_after))?;
+    set_field(&obj, "protocolFee", &int_value(protocol_fee))?;
+    set_field(&obj, "platformFee", &int_value(platform_fee))?;
+    set_field(&obj, "creatorFee", &int_value(creator_fee))?;
+    set_field(&obj, "shareFee", &int_value(share_fee))?;
+    set_field(&obj, "poolStatus", &JsValue::from_f64(pool_status as f64))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the Pump.fun `set_params` governance instruction.
+/// Fields appended by newer program versions are ignored unless `strict = true`.
+#[wasm_bindgen(js_name = "parsePumpFunSetParams")]
+pub fn parse_pump_fun_set_params(data: &[u8], strict: Option<bool>) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_PUMP_SET_PARAMS)?;
+    let args = borsh_decode::<PumpFunSetParamsArgs>(buf, rejects_trailing(strict))?;
+
+    let obj = Object::new();
+    set_field(&obj, "feeRecipient", &pubkey_value(args.fee_recipient))?;
+    set_field(
+        &obj,
+        "initialVirtualTokenReserves",
+        &int_value(args.initial_virtual_token_reserves),
+    )?;
+    set_field(
+        &obj,
+        "initialVirtualSolReserves",
+        &int_value(args.initial_virtual_sol_reserves),
+    )?;
+    set_field(
+        &obj,
+        "initialRealTokenReserves",
+        &int_value(args.initial_real_token_reserves),
+    )?;
+    set_field(
+        &obj,
+        "tokenTotalSupply",
+        &int_value(args.token_total_supply),
+    )?;
+    set_field(&obj, "feeBasisPoints", &int_value(args.fee_basis_points))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the Pump.fun admin instructions `extend_account`,
+/// `set_creator`, `admin_set_creator` and `update_global_authority`. Returns
+/// `{ instruction, ... }` with the args and affected accounts, so monitors
+/// can flag protocol changes. The instruction is picked by discriminator, or
+/// by name via `instruction` for data whose header is unknown (only accepted
+/// with `setDiscriminatorCheck(false)`).
+#[wasm_bindgen(js_name = "parsePumpFunAdmin")]
+pub fn parse_pump_fun_admin(
+    ix_data: &[u8],
+    accounts: JsValue,
+    instruction: Option<String>,
+) -> Result<JsValue, JsValue> {
+    const ADMIN_IXS: &[(&str, [u8; 8])] = &[
+        ("extend_account", D_PUMP_EXTEND_ACCOUNT),
+        ("set_creator", D_PUMP_SET_CREATOR),
+        ("admin_set_creator", D_PUMP_ADMIN_SET_CREATOR),
+        ("update_global_authority", D_PUMP_UPDATE_GLOBAL_AUTHORITY),
+    ];
+    let name = match instruction.as_deref() {
+        Some(name) => name,
+        None => known_instruction("pump_fun", ix_data).unwrap_or_default(),
     };
+    let (instruction, disc) = ADMIN_IXS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .ok_or_else(|| JsValue::from_str("not a Pump.fun admin ix"))?;
+    let buf = payload_checked(ix_data, disc)?;
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i).map(|k| JsValue::from_str(&k));
+    let mut off = 0;
 
-    to_value(&out).map_err(|e| JsValue::from_str(&format!("serde: {}", e)))
+    let obj = Object::new();
+    match *instruction {
+        // 0 = account, 1 = user
+        "extend_account" => {
+            set_field(&obj, "account", &get(0)?)?;
+            set_field(&obj, "user", &get(1)?)?;
+        }
+        // 0 = set_creator_authority, 2 = mint, 4 = bonding_curve
+        "set_creator" => {
+            let creator = read_pubkey_js(buf, &mut off)?;
+            set_field(&obj, "creator", &creator)?;
+            set_field(&obj, "authority", &get(0)?)?;
+            set_field(&obj, "mint", &get(2)?)?;
+            set_field(&obj, "bondingCurve", &get(4)?)?;
+        }
+        // 0 = admin_set_creator_authority, 2 = mint, 3 = bonding_curve
+        "admin_set_creator" => {
+            let creator = read_pubkey_js(buf, &mut off)?;
+            set_field(&obj, "creator", &creator)?;
+            set_field(&obj, "authority", &get(0)?)?;
+            set_field(&obj, "mint", &get(2)?)?;
+            set_field(&obj, "bondingCurve", &get(3)?)?;
+        }
+        // 0 = global, 1 = authority, 2 = new_authority
+        "update_global_authority" => {
+            set_field(&obj, "global", &get(0)?)?;
+            set_field(&obj, "authority", &get(1)?)?;
+            set_field(&obj, "newAuthority", &get(2)?)?;
+        }
+        _ => unreachable!("listed in ADMIN_IXS"),
+    }
+    set_field(&obj, "instruction", &JsValue::from_str(instruction))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Pump.fun `collect_creator_fee`. The instruction
+/// has no args (the vault is drained in full), so only the creator and vault
+/// accounts are returned; the amount is in the balance change of the vault.
+/// `set_creator` is covered by `parsePumpFunAdmin`.
+#[wasm_bindgen(js_name = "parsePumpFunCollectCreatorFee")]
+pub fn parse_pump_fun_collect_creator_fee(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    payload_checked(ix_data, &D_PUMP_COLLECT_CREATOR_FEE)?;
+    let accs = accounts_array(&accounts)?;
+    // Indices per IDL: 0 = creator, 1 = creator_vault
+    let get = |i: u32| account_at(&accs, i).map(|k| JsValue::from_str(&k));
+
+    let obj = Object::new();
+    set_field(&obj, "instruction", &"collect_creator_fee".into())?;
+    set_field(&obj, "creator", &get(0)?)?;
+    set_field(&obj, "creatorVault", &get(1)?)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Share of `target` raised, clamped to 0–100; a completed curve is 100.
+/// A zero target has no meaningful progress and is an error.
+fn progress_percent(raised: u64, target: u64, complete: bool) -> Result<f64, JsValue> {
+    if target == 0 {
+        return Err(JsValue::from_str("Graduation target must be non-zero"));
+    }
+    if complete {
+        return Ok(100.0);
+    }
+    Ok((raised as f64 / target as f64 * 100.0).min(100.0))
+}
+
+/// WASM-exported parser for Pump.fun-style curve state using JS BigInt.
+/// Pass `with_remainder = true` to add the unconsumed bytes as `remainderHex`,
+/// and `with_progress = true` to add `progressPercent`: real SOL reserves
+/// against the graduation target (~85 SOL, see `setGraduationThreshold`).
+#[wasm_bindgen(js_name = "parsePumpFunCurveState")]
+pub fn parse_pump_fun_curve_state(
+    data: &[u8],
+    with_remainder: Option<bool>,
+    with_progress: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_BONDING_CURVE)?;
+    // One upfront check instead of failing midway; newer accounts append a
+    // creator, so only the minimum is enforced
+    if buf.len() < CURVE_STATE_MIN_LEN {
+        return Err(JsValue::from_str(&format!(
+            "Curve state too short: {} bytes after the discriminator, need at least {}",
+            buf.len(),
+            CURVE_STATE_MIN_LEN
+        )));
+    }
+    let mut off = 0;
+
+    // Read Pump.fun u64 reserves in original order
+    let virtual_token_reserves = read_u64(buf, &mut off)?;
+    let virtual_sol_reserves = read_u64(buf, &mut off)?;
+    let real_token_reserves = read_u64(buf, &mut off)?;
+    let real_sol_reserves = read_u64(buf, &mut off)?;
+    let token_total_supply = read_u64(buf, &mut off)?;
+
+    // Read completion flag (bool)
+    let complete = read_u8(buf, &mut off)? != 0;
+
+    // Build JS object with BigInt and boolean
+    let obj = Object::new();
+    set_field(
+        &obj,
+        "virtual_token_reserves",
+        &int_value(virtual_token_reserves),
+    )?;
+    set_field(
+        &obj,
+        "virtual_sol_reserves",
+        &int_value(virtual_sol_reserves),
+    )?;
+    set_field(&obj, "real_token_reserves", &int_value(real_token_reserves))?;
+    set_field(&obj, "real_sol_reserves", &int_value(real_sol_reserves))?;
+    set_field(&obj, "token_total_supply", &int_value(token_total_supply))?;
+    set_field(&obj, "complete", &JsValue::from_bool(complete))?;
+    if with_progress.unwrap_or(false) {
+        let target = graduation_threshold("pump_fun").unwrap_or_default();
+        let percent = progress_percent(real_sol_reserves, target, complete)?;
+        set_field(&obj, "progressPercent", &JsValue::from_f64(percent))?;
+    }
+    if with_remainder.unwrap_or(false) {
+        set_field(&obj, "remainderHex", &to_hex(&buf[off..]).into())?;
+    }
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the Pump.fun `Global` account: the parameters
+/// every new bonding curve starts from. `creatorFeeBasisPoints` is null on
+/// accounts written before creator fees were added.
+#[wasm_bindgen(js_name = "parsePumpFunGlobal")]
+pub fn parse_pump_fun_global(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_PUMP_GLOBAL)?;
+    let mut off = 0;
+
+    let initialized = read_u8(buf, &mut off)? != 0;
+    let authority = read_pubkey_js(buf, &mut off)?;
+    let fee_recipient = read_pubkey_js(buf, &mut off)?;
+    let initial_virtual_token_reserves = read_u64(buf, &mut off)?;
+    let initial_virtual_sol_reserves = read_u64(buf, &mut off)?;
+    let initial_real_token_reserves = read_u64(buf, &mut off)?;
+    let token_total_supply = read_u64(buf, &mut off)?;
+    let fee_basis_points = read_u64(buf, &mut off)?;
+    // withdraw_authority, enable_migrate, pool_migration_fee, then the
+    // creator fee appended for creator rewards
+    let creator_fee_basis_points = if checked_end(buf, off, 32 + 1 + 8 + 8).is_some() {
+        skip(buf, &mut off, 32 + 1 + 8)?;
+        JsValue::from_f64(read_u64(buf, &mut off)? as f64)
+    } else {
+        JsValue::NULL
+    };
+
+    let obj = Object::new();
+    set_field(&obj, "initialized", &JsValue::from_bool(initialized))?;
+    set_field(&obj, "authority", &authority)?;
+    set_field(&obj, "feeRecipient", &fee_recipient)?;
+    set_field(
+        &obj,
+        "initialVirtualTokenReserves",
+        &int_value(initial_virtual_token_reserves),
+    )?;
+    set_field(
+        &obj,
+        "initialVirtualSolReserves",
+        &int_value(initial_virtual_sol_reserves),
+    )?;
+    set_field(
+        &obj,
+        "initialRealTokenReserves",
+        &int_value(initial_real_token_reserves),
+    )?;
+    set_field(&obj, "tokenTotalSupply", &int_value(token_total_supply))?;
+    set_field(
+        &obj,
+        "feeBasisPoints",
+        &JsValue::from_f64(fee_basis_points as f64),
+    )?;
+    set_field(&obj, "creatorFeeBasisPoints", &creator_fee_basis_points)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Pump.fun curve state that also reads the `creator`
+/// key newer accounts append after `complete`. Old-layout accounts, too short
+/// to hold it, return `creator: null`.
+#[wasm_bindgen(js_name = "parsePumpFunCurveStateWithCreator")]
+pub fn parse_pump_fun_curve_state_with_creator(data: &[u8]) -> Result<JsValue, JsValue> {
+    let obj = parse_pump_fun_curve_state(data, None, None)?;
+    let creator = curve_creator(data)?;
+    set_field(&obj, "creator", &creator.unwrap_or(JsValue::NULL))?;
+
+    Ok(obj)
+}
+
+/// The `creator` newer BondingCurve accounts append after `complete`, or
+/// `None` for the old layout.
+fn curve_creator(data: &[u8]) -> Result<Option<JsValue>, JsValue> {
+    let buf = payload_checked(data, &D_ACC_BONDING_CURVE)?;
+    let mut off = CURVE_STATE_MIN_LEN;
+    if checked_end(buf, off, 32).is_none() {
+        return Ok(None);
+    }
+    read_pubkey_js(buf, &mut off).map(Some)
+}
+
+/// WASM-exported creator-fee info for a Pump.fun curve: `creator` from the
+/// curve account and `creatorVault` from the accounts of a trade on it. The
+/// vault is a PDA of the creator, taken from `accounts[vault_index]` (default
+/// 9, its index in `buy`; it is 8 in `sell`). Both are null for old curves
+/// with no creator; `creatorVault` is also null when `accounts` is null.
+#[wasm_bindgen(js_name = "parsePumpFunCreatorInfo")]
+pub fn parse_pump_fun_creator_info(
+    curve_data: &[u8],
+    accounts: JsValue,
+    vault_index: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let creator = curve_creator(curve_data)?;
+    let vault = match &creator {
+        Some(_) if !accounts.is_null() && !accounts.is_undefined() => {
+            let accs = accounts_array(&accounts)?;
+            JsValue::from_str(&account_at(&accs, vault_index.unwrap_or(9))?)
+        }
+        _ => JsValue::NULL,
+    };
+
+    let obj = Object::new();
+    set_field(&obj, "creator", &creator.unwrap_or(JsValue::NULL))?;
+    set_field(&obj, "creatorVault", &vault)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Raydium Launchpad PoolState using JS BigInt.
+/// `accumulators` holds the fee counters and vesting schedule as 8 raw u64s.
+/// `progressPercent` is `realQuote` against `totalQuoteFundRaising`, 100 once
+/// `isComplete`, and null when `totalQuoteFundRaising` is 0. Pass
+/// `with_remainder = true` to add the unconsumed bytes as `remainderHex`, and
+/// `fields` to return only those keys.
+#[wasm_bindgen(js_name = "parseLaunchpadPoolState")]
+pub fn parse_launchpad_pool_state(
+    data: &[u8],
+    with_remainder: Option<bool>,
+    fields: Option<Vec<String>>,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_POOL_STATE)?; // strips 8-byte Anchor discriminator
+    let f = FieldFilter(fields);
+    let mut off = 0;
+
+    let epoch = read_u64(buf, &mut off)?;
+    skip(buf, &mut off, 1)?; // auth_bump
+    let status = read_u8(buf, &mut off)?;
+    let base_decimals = read_u8(buf, &mut off)?;
+    let quote_decimals = read_u8(buf, &mut off)?;
+    let migrate_type = read_u8(buf, &mut off)?;
+
+    let supply = read_u64(buf, &mut off)?;
+    let total_base_sell = read_u64(buf, &mut off)?;
+    let virtual_base = read_u64(buf, &mut off)?;
+    let virtual_quote = read_u64(buf, &mut off)?;
+    let real_base = read_u64(buf, &mut off)?;
+    let real_quote = read_u64(buf, &mut off)?;
+    let total_quote_fund_raising = read_u64(buf, &mut off)?;
+
+    // quote_protocol_fee, platform_fee, migrate_fee, then the 5 u64s of
+    // vesting_schedule (total_locked_amount, cliff_period, unlock_period,
+    // start_time, allocated_share_amount)
+    let accumulators = read_u64_array::<8>(buf, &mut off)?;
+
+    let global_config = f.pubkey("globalConfig", buf, &mut off)?;
+    // skip platform_config and base_mint (2 pubkeys)
+    skip(buf, &mut off, 32 * 2)?;
+    let quote_mint = f.pubkey("quoteMint", buf, &mut off)?;
+
+    // Build JS object with key fields
+    let obj = Object::new();
+    f.set(&obj, "status", || JsValue::from_f64(status as f64))?;
+    f.set(&obj, "virtualBase", || int_value(virtual_base))?;
+    f.set(&obj, "globalConfig", || global_config)?;
+    f.set(&obj, "quoteMint", || quote_mint)?;
+    f.set(&obj, "virtualQuote", || int_value(virtual_quote))?;
+    f.set(&obj, "realBase", || int_value(real_base))?;
+    f.set(&obj, "realQuote", || int_value(real_quote))?;
+    f.set(&obj, "supply", || int_value(supply))?;
+    f.set(&obj, "totalBaseSell", || int_value(total_base_sell))?;
+    f.set(&obj, "totalQuoteFundRaising", || {
+        int_value(total_quote_fund_raising)
+    })?;
+    f.set(&obj, "baseDecimals", || {
+        JsValue::from_f64(base_decimals as f64)
+    })?;
+    f.set(&obj, "quoteDecimals", || {
+        JsValue::from_f64(quote_decimals as f64)
+    })?;
+    f.set(&obj, "migrateType", || {
+        JsValue::from_f64(migrate_type as f64)
+    })?;
+    f.set(&obj, "epoch", || int_value(epoch))?;
+    f.set(&obj, "accumulators", || {
+        accumulators
+            .into_iter()
+            .map(int_value)
+            .collect::<Array>()
+            .into()
+    })?;
+    // Status 0 is still fundraising; 1 (migrate) and 2 (trade) come after the
+    // goal is reached
+    let is_complete = status != LAUNCHPAD_STATUS_FUND || real_quote >= total_quote_fund_raising;
+    f.set(&obj, "isComplete", || JsValue::from_bool(is_complete))?;
+    // A pool with no fundraising goal has no progress to report
+    let progress = if total_quote_fund_raising == 0 {
+        JsValue::NULL
+    } else {
+        JsValue::from_f64(progress_percent(
+            real_quote,
+            total_quote_fund_raising,
+            is_complete,
+        )?)
+    };
+    f.set(&obj, "progressPercent", || progress)?;
+    if with_remainder.unwrap_or(false) {
+        set_field(&obj, "remainderHex", &to_hex(&buf[off..]).into())?;
+    }
+
+    Ok(JsValue::from(obj))
+}
+
+#[wasm_bindgen(js_name = "parseLaunchpadGlobalConfig")]
+pub fn parse_launchpad_global_config(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_GLOBAL_CONFIG)?;
+    let mut off = 0;
+
+    // Only read the curve_type field
+    skip(buf, &mut off, 8)?; // Skip the epoch (u64)
+    let curve_type = read_u8(buf, &mut off)?;
+
+    // Return curve_type as a JS object
+    let obj = Object::new();
+    set_field(&obj, "curveType", &JsValue::from_f64(curve_type as f64))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for a Raydium Launchpad VestingRecord account.
+/// The schedule itself (locked amount, cliff, unlock period) lives in the
+/// pool's PoolState; pass its data as `pool_data` to include it, or an empty
+/// array to return only the record.
+#[wasm_bindgen(js_name = "parseLaunchpadVesting")]
+pub fn parse_launchpad_vesting(data: &[u8], pool_data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_VESTING_RECORD)?;
+    let mut off = 0;
+
+    let epoch = read_u64(buf, &mut off)?;
+    let pool = read_pubkey_js(buf, &mut off)?;
+    let beneficiary = read_pubkey_js(buf, &mut off)?;
+    let claimed_amount = read_u64(buf, &mut off)?;
+    let token_share_amount = read_u64(buf, &mut off)?;
+
+    let obj = Object::new();
+    set_field(&obj, "epoch", &int_value(epoch))?;
+    set_field(&obj, "pool", &pool)?;
+    set_field(&obj, "beneficiary", &beneficiary)?;
+    set_field(&obj, "claimedAmount", &int_value(claimed_amount))?;
+    set_field(&obj, "tokenShareAmount", &int_value(token_share_amount))?;
+
+    if !pool_data.is_empty() {
+        let pool_buf = payload_checked(pool_data, &D_ACC_POOL_STATE)?;
+        // PoolState.vesting_schedule follows epoch, 5 u8 flags and 10 u64s
+        let mut off = 0;
+        skip(pool_buf, &mut off, 8 + 5 + 10 * 8)?;
+        let total_locked_amount = read_u64(pool_buf, &mut off)?;
+        let cliff_period = read_u64(pool_buf, &mut off)?;
+        let unlock_period = read_u64(pool_buf, &mut off)?;
+        let start_time = read_u64(pool_buf, &mut off)?;
+
+        set_field(&obj, "totalLockedAmount", &int_value(total_locked_amount))?;
+        set_field(&obj, "cliffPeriod", &int_value(cliff_period))?;
+        set_field(&obj, "unlockPeriod", &int_value(unlock_period))?;
+        set_field(&obj, "startTime", &int_value(start_time))?;
+    }
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the full Raydium Launchpad GlobalConfig (rates in 10^-6)
+#[wasm_bindgen(js_name = "parseLaunchpadGlobalConfigFull")]
+pub fn parse_launchpad_global_config_full(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_GLOBAL_CONFIG)?;
+    let mut off = 0;
+
+    let epoch = read_u64(buf, &mut off)?;
+    let curve_type = read_u8(buf, &mut off)?;
+    let index = read_u16(buf, &mut off)?;
+    let migrate_fee = read_u64(buf, &mut off)?;
+    let trade_fee_rate = read_u64(buf, &mut off)?;
+    let max_share_fee_rate = read_u64(buf, &mut off)?;
+    let min_base_supply = read_u64(buf, &mut off)?;
+    let max_lock_rate = read_u64(buf, &mut off)?;
+    let min_base_sell_rate = read_u64(buf, &mut off)?;
+    let min_base_migrate_rate = read_u64(buf, &mut off)?;
+    let min_quote_fund_raising = read_u64(buf, &mut off)?;
+    let quote_mint = read_pubkey_js(buf, &mut off)?;
+    let protocol_fee_owner = read_pubkey_js(buf, &mut off)?;
+    let migrate_fee_owner = read_pubkey_js(buf, &mut off)?;
+    let migrate_to_amm_wallet = read_pubkey_js(buf, &mut off)?;
+    let migrate_to_cpswap_wallet = read_pubkey_js(buf, &mut off)?;
+
+    let obj = Object::new();
+    set_field(&obj, "epoch", &int_value(epoch))?;
+    set_field(&obj, "curveType", &JsValue::from_f64(curve_type as f64))?;
+    set_field(&obj, "index", &JsValue::from_f64(index as f64))?;
+    set_field(&obj, "migrateFee", &int_value(migrate_fee))?;
+    set_field(&obj, "tradeFeeRate", &int_value(trade_fee_rate))?;
+    set_field(&obj, "maxShareFeeRate", &int_value(max_share_fee_rate))?;
+    set_field(&obj, "minBaseSupply", &int_value(min_base_supply))?;
+    set_field(&obj, "maxLockRate", &int_value(max_lock_rate))?;
+    set_field(&obj, "minBaseSellRate", &int_value(min_base_sell_rate))?;
+    set_field(
+        &obj,
+        "minBaseMigrateRate",
+        &int_value(min_base_migrate_rate),
+    )?;
+    set_field(
+        &obj,
+        "minQuoteFundRaising",
+        &int_value(min_quote_fund_raising),
+    )?;
+    set_field(&obj, "quoteMint", &quote_mint)?;
+    set_field(&obj, "feeOwner", &protocol_fee_owner)?;
+    set_field(&obj, "migrateFeeOwner", &migrate_fee_owner)?;
+    set_field(&obj, "migrateToAmmWallet", &migrate_to_amm_wallet)?;
+    set_field(&obj, "migrateToCpswapWallet", &migrate_to_cpswap_wallet)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for a Raydium Launchpad PlatformConfig account (the
+/// `platform_config` a PoolState points to). Reads up to `creator_fee_rate`;
+/// rates and scales are raw u64s as stored on-chain.
+#[wasm_bindgen(js_name = "parseLaunchpadPlatformConfig")]
+pub fn parse_launchpad_platform_config(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_PLATFORM_CONFIG)?;
+    let mut off = 0;
+
+    let epoch = read_u64(buf, &mut off)?;
+    let platform_fee_wallet = read_pubkey_js(buf, &mut off)?;
+    let platform_nft_wallet = read_pubkey_js(buf, &mut off)?;
+    let platform_scale = read_u64(buf, &mut off)?;
+    let creator_scale = read_u64(buf, &mut off)?;
+    let burn_scale = read_u64(buf, &mut off)?;
+    let fee_rate = read_u64(buf, &mut off)?;
+    let name = read_fixed_string(buf, &mut off, 64)?;
+    let web = read_fixed_string(buf, &mut off, 256)?;
+    let img = read_fixed_string(buf, &mut off, 256)?;
+    let cpswap_config = read_pubkey_js(buf, &mut off)?;
+    let creator_fee_rate = read_u64(buf, &mut off)?;
+
+    let obj = Object::new();
+    set_field(&obj, "epoch", &int_value(epoch))?;
+    set_field(&obj, "platformFeeWallet", &platform_fee_wallet)?;
+    set_field(&obj, "platformNftWallet", &platform_nft_wallet)?;
+    set_field(&obj, "platformScale", &int_value(platform_scale))?;
+    set_field(&obj, "creatorScale", &int_value(creator_scale))?;
+    set_field(&obj, "burnScale", &int_value(burn_scale))?;
+    set_field(&obj, "feeRate", &int_value(fee_rate))?;
+    set_field(&obj, "name", &JsValue::from_str(&name))?;
+    set_field(&obj, "web", &JsValue::from_str(&web))?;
+    set_field(&obj, "img", &JsValue::from_str(&img))?;
+    set_field(&obj, "cpswapConfig", &cpswap_config)?;
+    set_field(&obj, "creatorFeeRate", &int_value(creator_fee_rate))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Decode Meteora DBC `InitializePoolParameters`, with or without the 8-byte header.
+fn decode_meteora_params(ix_data: &[u8]) -> Result<InitializePoolParameters, JsValue> {
+    // Try decode assuming discriminator is present (skip 8), then fallback to raw.
+    let try_decode = |buf: &[u8]| borsh_decode::<InitializePoolParameters>(buf, false);
+    // Both paths can deserialize the same bytes; only accept metadata within
+    // Metaplex limits and free of control characters.
+    let plausible = |p: &InitializePoolParameters| {
+        let ok = |s: &str, max: usize| s.len() <= max && !s.chars().any(char::is_control);
+        ok(&p.name, 32) && ok(&p.symbol, 10) && ok(&p.uri, 200)
+    };
+
+    // With checking on, only a DBC initialize header is accepted and there
+    // is no header-less fallback.
+    let checked = CHECK_DISCRIMINATOR.with(Cell::get);
+    let is_init = |head: &[u8]| head == D_METEORA_INIT_SPL || head == D_METEORA_INIT_T22;
+
+    if checked {
+        let body = payload(ix_data)?;
+        if !is_init(&ix_data[..8]) {
+            return Err(JsValue::from_str("discriminator mismatch"));
+        }
+        return try_decode(body);
+    }
+
+    // Attempt A: data includes 8-byte header
+    let with_header = payload(ix_data).ok().and_then(|body| try_decode(body).ok());
+    match with_header {
+        Some(args) if plausible(&args) => Ok(args),
+        // Attempt B: data already header-less; decode from start. Fall back
+        // to an implausible header decode rather than failing outright.
+        with_header => match (try_decode(ix_data), with_header) {
+            (Ok(raw), _) if plausible(&raw) => Ok(raw),
+            (_, Some(args)) => Ok(args),
+            (raw, None) => raw,
+        },
+    }
+}
+
+/// Map DBC initialize args and accounts (2 = creator, 3 = base_mint,
+/// 5 = pool) into a `TokenCreate`.
+fn meteora_token_create(
+    platform: &'static str,
+    ix_data: &[u8],
+    args: InitializePoolParameters,
+    accs: &Array,
+) -> Result<TokenCreate, JsValue> {
+    Ok(TokenCreate {
+        platform,
+        // Header-less data does not say which variant it was
+        instruction: known_instruction("meteora_dbc", ix_data).unwrap_or("initialize_virtual_pool"),
+        name: args.name,
+        symbol: args.symbol,
+        uri: Some(args.uri),
+        mint: Some(account_at(accs, 3)?),
+        pool: Some(account_at(accs, 5)?),
+        developer: Some(account_at(accs, 2)?),
+    })
+}
+
+/// WASM-exported parser for Meteora DBC `initialize_virtual_pool_*`.
+/// Targets the name/symbol/uri args layout shared by all DBC IDL versions.
+#[wasm_bindgen(js_name = "parseMeteoraInitialize")]
+pub fn parse_meteora_initialize(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    let args = decode_meteora_params(ix_data)?;
+
+    // 2) Accounts aus Webhook/WS: Array von Base58-Strings
+    let accs = accounts_array(&accounts)?;
+    if accs.length() < 6 {
+        return Err(JsValue::from_str(&format!(
+            "Meteora initialize needs accounts at indices 2, 3, 5 (got {})",
+            accs.length()
+        )));
+    }
+
+    // Indizes lt. IDL:
+    // 2 = creator, 3 = base_mint, 5 = pool (PoolState)
+    meteora_token_create("meteora_dbc", ix_data, args, &accs)?.to_js()
+}
+
+/// WASM-exported parser for Meteora DBC `initialize_virtual_pool_*` that also
+/// returns the curve config. Targets DBC IDL v0.1.6 (`idls/meteora_idl.json`),
+/// where the curve parameters live in the `config` PoolConfig account rather
+/// than in the instruction args.
+#[wasm_bindgen(js_name = "parseMeteoraInitializeWithConfig")]
+pub fn parse_meteora_initialize_with_config(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    let args = decode_meteora_params(ix_data)?;
+    let token_type = match ix_data.get(..8) {
+        Some(head) if head == D_METEORA_INIT_SPL => Some("spl"),
+        Some(head) if head == D_METEORA_INIT_T22 => Some("token2022"),
+        _ => None,
+    };
+
+    let accs = accounts_array(&accounts)?;
+    if accs.length() < 8 {
+        return Err(JsValue::from_str(&format!(
+            "Meteora initialize needs accounts at indices 0, 2-7 (got {})",
+            accs.length()
+        )));
+    }
+    let get = |i: u32| account_at(&accs, i);
+
+    let out = meteora_token_create("meteora_dbc", ix_data, args, &accs)?.to_js()?;
+    // 0 = config (PoolConfig holding the curve), 4 = quote_mint, 6/7 = vaults
+    set_field(&out, "config", &JsValue::from_str(&get(0)?))?;
+    set_field(&out, "quote_mint", &JsValue::from_str(&get(4)?))?;
+    set_field(&out, "base_vault", &JsValue::from_str(&get(6)?))?;
+    set_field(&out, "quote_vault", &JsValue::from_str(&get(7)?))?;
+    // "spl" | "token2022", null if header-less
+    set_field(
+        &out,
+        "token_type",
+        &token_type.map_or(JsValue::NULL, JsValue::from_str),
+    )?;
+
+    Ok(out)
+}
+
+/// WASM-exported parser for Daos.fun launches. Daos.fun creates tokens through
+/// Meteora DBC with its own PoolConfig, so the args are DBC's
+/// `InitializePoolParameters`; the fundraising goal (migration quote threshold)
+/// and duration are config-level and not part of the instruction.
+#[wasm_bindgen(js_name = "parseDaosFunCreate")]
+pub fn parse_daos_fun_create(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    let args = decode_meteora_params(ix_data)?;
+
+    let accs = accounts_array(&accounts)?;
+    if accs.length() < 6 {
+        return Err(JsValue::from_str(&format!(
+            "Daos.fun create needs accounts at indices 0, 2, 3, 5 (got {})",
+            accs.length()
+        )));
+    }
+    let get = |i: u32| account_at(&accs, i);
+
+    let out = meteora_token_create("daos_fun", ix_data, args, &accs)?.to_js()?;
+    // 0 = the Daos.fun PoolConfig
+    set_field(&out, "dao_config", &JsValue::from_str(&get(0)?))?;
+
+    Ok(out)
+}
+
+/// Account index of the optional share-fee (referral) receiver on Launchpad
+/// `buy_exact_in` / `sell_exact_in`: the first account after the IDL's 15.
+const LAUNCHPAD_SHARE_FEE_RECEIVER: u32 = 15;
+
+/// Shared decoder for Raydium Launchpad `buy_exact_in` / `sell_exact_in`.
+/// With `strict`, bytes after `share_fee_rate` are an error.
+/// `shareFeeReceiver` is null when the trade passes no remaining account.
+fn parse_launchpad_trade(
+    data: &[u8],
+    accounts: JsValue,
+    discriminator: &[u8; 8],
+    strict: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, discriminator)?;
+    let mut off = 0;
+
+    let amount_in = read_u64(buf, &mut off)?;
+    let minimum_amount_out = read_u64(buf, &mut off)?;
+    let share_fee_rate = read_u64(buf, &mut off)?;
+    ensure_consumed(buf, off, strict)?;
+
+    // Indices per IDL:
+    // 0 = payer, 3 = platform_config, 4 = pool_state, 9 = base_token_mint,
+    // 10 = quote_token_mint; 15 = share fee receiver (remaining account, only
+    // passed by referred trades)
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+    let share_fee_receiver = if accs.length() > LAUNCHPAD_SHARE_FEE_RECEIVER {
+        JsValue::from_str(&get(LAUNCHPAD_SHARE_FEE_RECEIVER)?)
+    } else {
+        JsValue::NULL
+    };
+
+    let obj = Object::new();
+    set_field(&obj, "amountIn", &int_value(amount_in))?;
+    set_field(&obj, "minimumAmountOut", &int_value(minimum_amount_out))?;
+    set_field(&obj, "shareFeeRate", &int_value(share_fee_rate))?;
+    set_field(&obj, "shareFeeReceiver", &share_fee_receiver)?;
+    set_field(&obj, "payer", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "platformConfig", &JsValue::from_str(&get(3)?))?;
+    set_field(&obj, "poolState", &JsValue::from_str(&get(4)?))?;
+    set_field(&obj, "baseMint", &JsValue::from_str(&get(9)?))?;
+    set_field(&obj, "quoteMint", &JsValue::from_str(&get(10)?))?;
+    // Both sides are exact-in: amount_in bounded by minimum_amount_out
+    let side = if discriminator == &D_RAY_BUY_EXACT_IN {
+        "buy"
+    } else {
+        "sell"
+    };
+    set_trade_limits(&obj, side, amount_in, minimum_amount_out, "min_out")?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Raydium Launchpad `create_vesting_account`. The
+/// only arg is the beneficiary's `shareAmount`; the lock amount, cliff and
+/// unlock period are pool-wide, set by `VestingParam` at initialize and held
+/// in the PoolState's vesting schedule.
+#[wasm_bindgen(js_name = "parseLaunchpadCreateVesting")]
+pub fn parse_launchpad_create_vesting(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(ix_data, &D_RAY_CREATE_VESTING)?;
+    let mut off = 0;
+    let share_amount = read_u64(buf, &mut off)?;
+
+    // Indices per IDL:
+    // 0 = creator, 1 = beneficiary, 2 = pool_state, 3 = vesting_record
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "shareAmount", &int_value(share_amount))?;
+    set_field(&obj, "creator", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "beneficiary", &JsValue::from_str(&get(1)?))?;
+    set_field(&obj, "poolState", &JsValue::from_str(&get(2)?))?;
+    set_field(&obj, "vestingRecord", &JsValue::from_str(&get(3)?))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Raydium Launchpad `claim_vested_token`. The
+/// instruction has no args: the program computes the unlocked amount, so
+/// `amount` is always null and the claim size must come from the token
+/// balance change of `userBaseToken`.
+#[wasm_bindgen(js_name = "parseLaunchpadClaimVested")]
+pub fn parse_launchpad_claim_vested(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    payload_checked(ix_data, &D_RAY_CLAIM_VESTED)?;
+
+    // Indices per IDL:
+    // 0 = beneficiary, 2 = pool_state, 3 = vesting_record,
+    // 5 = user_base_token, 6 = base_token_mint
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "amount", &JsValue::NULL)?;
+    set_field(&obj, "beneficiary", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "poolState", &JsValue::from_str(&get(2)?))?;
+    set_field(&obj, "vestingRecord", &JsValue::from_str(&get(3)?))?;
+    set_field(&obj, "userBaseToken", &JsValue::from_str(&get(5)?))?;
+    set_field(&obj, "baseMint", &JsValue::from_str(&get(6)?))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Shared output of the Launchpad migrate instructions: the source pool and
+/// destination accounts, with `destination` naming the target program.
+fn launchpad_migration(
+    accs: &Array,
+    destination: &str,
+    pool_state: u32,
+    pool: u32,
+    lp_mint: u32,
+) -> Result<Object, JsValue> {
+    let get = |i: u32| account_at(accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "destination", &JsValue::from_str(destination))?;
+    set_field(&obj, "poolState", &JsValue::from_str(&get(pool_state)?))?;
+    set_field(&obj, "baseMint", &JsValue::from_str(&get(1)?))?;
+    set_field(&obj, "quoteMint", &JsValue::from_str(&get(2)?))?;
+    set_field(&obj, "pool", &JsValue::from_str(&get(pool)?))?;
+    set_field(&obj, "lpMint", &JsValue::from_str(&get(lp_mint)?))?;
+
+    Ok(obj)
+}
+
+/// WASM-exported parser for Raydium Launchpad `migrate_to_amm`, the
+/// graduation to a Raydium AMM v4 pool on an OpenBook market.
+#[wasm_bindgen(js_name = "parseRaydiumLaunchpadMigrateToAmm")]
+pub fn parse_raydium_launchpad_migrate_to_amm(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(ix_data, &D_RAY_MIGRATE_TO_AMM)?;
+    let mut off = 0;
+
+    let base_lot_size = read_u64(buf, &mut off)?;
+    let quote_lot_size = read_u64(buf, &mut off)?;
+    let market_vault_signer_nonce = read_u8(buf, &mut off)?;
+
+    // Indices per IDL:
+    // 1 = base_mint, 2 = quote_mint, 4 = market, 13 = amm_pool,
+    // 16 = amm_lp_mint, 23 = pool_state
+    let accs = accounts_array(&accounts)?;
+    let obj = launchpad_migration(&accs, "raydium_amm_v4", 23, 13, 16)?;
+    set_field(&obj, "market", &JsValue::from_str(&account_at(&accs, 4)?))?;
+    set_field(&obj, "baseLotSize", &int_value(base_lot_size))?;
+    set_field(&obj, "quoteLotSize", &int_value(quote_lot_size))?;
+    set_field(
+        &obj,
+        "marketVaultSignerNonce",
+        &JsValue::from_f64(market_vault_signer_nonce as f64),
+    )?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Raydium Launchpad `migrate_to_cpswap`, the
+/// graduation to a Raydium CPMM pool. The instruction has no args.
+#[wasm_bindgen(js_name = "parseRaydiumLaunchpadMigrateToCpmm")]
+pub fn parse_raydium_launchpad_migrate_to_cpmm(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    payload_checked(ix_data, &D_RAY_MIGRATE_TO_CPSWAP)?;
+
+    // Indices per IDL:
+    // 1 = base_mint, 2 = quote_mint, 3 = platform_config,
+    // 5 = cpswap_pool, 7 = cpswap_lp_mint, 17 = pool_state
+    let accs = accounts_array(&accounts)?;
+    let obj = launchpad_migration(&accs, "raydium_cpmm", 17, 5, 7)?;
+    set_field(
+        &obj,
+        "platformConfig",
+        &JsValue::from_str(&account_at(&accs, 3)?),
+    )?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Raydium Launchpad `buy_exact_in`
+#[wasm_bindgen(js_name = "parseRaydiumLaunchpadBuyExactIn")]
+pub fn parse_raydium_launchpad_buy_exact_in(
+    data: &[u8],
+    accounts: JsValue,
+    strict: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    parse_launchpad_trade(data, accounts, &D_RAY_BUY_EXACT_IN, strict)
+}
+
+/// WASM-exported parser for Raydium Launchpad `sell_exact_in`
+#[wasm_bindgen(js_name = "parseRaydiumLaunchpadSellExactIn")]
+pub fn parse_raydium_launchpad_sell_exact_in(
+    data: &[u8],
+    accounts: JsValue,
+    strict: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    parse_launchpad_trade(data, accounts, &D_RAY_SELL_EXACT_IN, strict)
+}
+
+// INFO: Encoders
+/// Deserialize a JS object via serde and Borsh-encode it behind `discriminator`.
+fn encode_with<T: DeserializeOwned + BorshSerialize>(
+    obj: JsValue,
+    discriminator: &[u8],
+) -> Result<Vec<u8>, JsValue> {
+    if discriminator.len() != 8 {
+        return Err(JsValue::from_str("Discriminator must be 8 bytes"));
+    }
+    let value: T =
+        from_value(obj).map_err(|e| JsValue::from_str(&format!("Invalid object: {}", e)))?;
+
+    let mut out = discriminator.to_vec();
+    value
+        .serialize(&mut out)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))?;
+    Ok(out)
+}
+
+/// Encode `{ name, symbol, uri }` as Meteora `InitializePoolParameters` data
+#[wasm_bindgen(js_name = "encodeInitializePoolParameters")]
+pub fn encode_initialize_pool_parameters(
+    obj: JsValue,
+    discriminator: &[u8],
+) -> Result<Vec<u8>, JsValue> {
+    encode_with::<InitializePoolParameters>(obj, discriminator)
+}
+
+/// Encode a Raydium Launchpad `CurveParams`, e.g. `{ Constant: { data: {...} } }`
+#[wasm_bindgen(js_name = "encodeCurveParams")]
+pub fn encode_curve_params(obj: JsValue, discriminator: &[u8]) -> Result<Vec<u8>, JsValue> {
+    encode_with::<CurveParams>(obj, discriminator)
+}
+
+// INFO: Pricing helpers
+/// `amount * bps / 10_000`, rounded up like the Pump.fun program's `ceil_div`.
+fn bps_fee(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128).div_ceil(10_000) as u64
+}
+
+/// `(protocol_fee, creator_fee)` on the curve's `sol_amount`, rejecting basis
+/// points above 10_000.
+fn pump_fun_fees(
+    sol_amount: u64,
+    fee_bps: u16,
+    creator_fee_bps: u16,
+) -> Result<(u64, u64), JsValue> {
+    if fee_bps > 10_000 || creator_fee_bps > 10_000 {
+        return Err(JsValue::from_str("Fee bps must be at most 10000"));
+    }
+    Ok((
+        bps_fee(sol_amount, fee_bps),
+        bps_fee(sol_amount, creator_fee_bps),
+    ))
+}
+
+/// Compute Pump.fun protocol and creator fees for a trade whose curve moved
+/// `solAmount` lamports (the TradeEvent's `sol_amount`). As on-chain, each fee
+/// is rounded up separately from `solAmount`; a buy pays them on top
+/// (`userAmount = solAmount + fees`) and a sell has them deducted from the
+/// proceeds (`userAmount = solAmount - fees`). `side` is `"buy"` or `"sell"`.
+#[wasm_bindgen(js_name = "computePumpFunFees")]
+pub fn compute_pump_fun_fees(
+    sol_amount: u64,
+    fee_bps: u16,
+    creator_fee_bps: u16,
+    side: &str,
+) -> Result<JsValue, JsValue> {
+    let buy = match side {
+        "buy" => true,
+        "sell" => false,
+        _ => return Err(JsValue::from_str("side must be \"buy\" or \"sell\"")),
+    };
+    let (protocol_fee, creator_fee) = pump_fun_fees(sol_amount, fee_bps, creator_fee_bps)?;
+    let user_amount = if buy {
+        sol_amount
+            .checked_add(protocol_fee)
+            .and_then(|v| v.checked_add(creator_fee))
+            .ok_or_else(|| JsValue::from_str("Amount overflows u64"))?
+    } else {
+        sol_amount
+            .checked_sub(protocol_fee)
+            .and_then(|v| v.checked_sub(creator_fee))
+            .ok_or_else(|| JsValue::from_str("Fees exceed amount"))?
+    };
+
+    let obj = Object::new();
+    set_field(&obj, "protocolFee", &int_value(protocol_fee))?;
+    set_field(&obj, "creatorFee", &int_value(creator_fee))?;
+    set_field(&obj, "userAmount", &int_value(user_amount))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Largest curve `sol_amount` a buy can cover when the user pays at most
+/// `max_sol_cost` including fees, with the fees on it.
+fn pump_fun_max_buy(
+    max_sol_cost: u64,
+    fee_bps: u16,
+    creator_fee_bps: u16,
+) -> Result<(u64, u64, u64), JsValue> {
+    let total_bps = 10_000 + fee_bps as u128 + creator_fee_bps as u128;
+    // Floor of the fee-free inverse; rounding the fees up can overshoot by a
+    // lamport or two, so step back until it fits
+    let mut sol = (max_sol_cost as u128 * 10_000 / total_bps) as u64;
+    loop {
+        let (protocol_fee, creator_fee) = pump_fun_fees(sol, fee_bps, creator_fee_bps)?;
+        if sol as u128 + protocol_fee as u128 + creator_fee as u128 <= max_sol_cost as u128 {
+            return Ok((sol, protocol_fee, creator_fee));
+        }
+        sol -= 1;
+    }
+}
+
+/// `(amount, max_sol_cost)` from Pump.fun `buy` instruction data.
+fn read_pump_fun_buy(ix_data: &[u8]) -> Result<(u64, u64), JsValue> {
+    let buf = payload_checked(ix_data, &D_BUY)?;
+    let mut off = 0;
+    let amount = read_u64(buf, &mut off)?;
+    let max_sol_cost = read_u64(buf, &mut off)?;
+    Ok((amount, max_sol_cost))
+}
+
+/// WASM-exported parser for a Pump.fun `create` instruction and, if given,
+/// the dev `buy` bundled with it. Returns the `TokenCreate` shape (with the
+/// `creator` arg newer creates carry as `developer`) plus `initialBuy:
+/// { tokenAmount, maxSolCost }`, or `initialBuy: null` without a buy.
+#[wasm_bindgen(js_name = "parsePumpFunCreateAndBuy")]
+pub fn parse_pump_fun_create_and_buy(
+    create_ix: &[u8],
+    buy_ix: Option<Vec<u8>>,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(create_ix, &D_PUMP_CREATE)?;
+    let mut off = 0;
+
+    let name = read_string(buf, &mut off)?;
+    let symbol = read_string(buf, &mut off)?;
+    let uri = read_string(buf, &mut off)?;
+    // `creator` was appended to the args for creator fees
+    let creator = if checked_end(buf, off, 32).is_some() {
+        Some(read_pubkey(buf, &mut off)?)
+    } else {
+        None
+    };
+
+    let mut out = TokenCreate::new("pump_fun", "create", name, symbol);
+    out.uri = Some(uri);
+    out.developer = creator;
+    let out = out.to_js()?;
+
+    let initial_buy = match buy_ix {
+        Some(ix) => {
+            let (amount, max_sol_cost) = read_pump_fun_buy(&ix)?;
+            let buy = Object::new();
+            set_field(&buy, "tokenAmount", &int_value(amount))?;
+            set_field(&buy, "maxSolCost", &int_value(max_sol_cost))?;
+            JsValue::from(buy)
+        }
+        None => JsValue::NULL,
+    };
+    set_field(&out, "initialBuy", &initial_buy)?;
+
+    Ok(out)
+}
+
+/// WASM-exported parser for Pump.fun `buy` with the fees applied. The
+/// instruction only carries `maxSolCost`, which includes the fees charged on
+/// top of the curve amount, so the outputs are upper bounds:
+/// `maxNetSolAmount` is the largest curve amount that `maxSolCost` covers and
+/// `maxProtocolFee` / `maxCreatorFee` are the fees on it, as in
+/// `computePumpFunFees`. The actual spend is in the matching TradeEvent.
+#[wasm_bindgen(js_name = "parsePumpFunBuyWithFees")]
+pub fn parse_pump_fun_buy_with_fees(
+    ix_data: &[u8],
+    fee_bps: u16,
+    creator_fee_bps: u16,
+) -> Result<JsValue, JsValue> {
+    let (amount, max_sol_cost) = read_pump_fun_buy(ix_data)?;
+    let (max_net_sol_amount, protocol_fee, creator_fee) =
+        pump_fun_max_buy(max_sol_cost, fee_bps, creator_fee_bps)?;
+
+    let obj = Object::new();
+    set_field(&obj, "tokenAmount", &int_value(amount))?;
+    set_field(&obj, "maxSolCost", &int_value(max_sol_cost))?;
+    // Exact tokens out, bounded by the SOL paid in
+    set_trade_limits(&obj, "buy", amount, max_sol_cost, "max_in")?;
+    set_field(&obj, "maxProtocolFee", &int_value(protocol_fee))?;
+    set_field(&obj, "maxCreatorFee", &int_value(creator_fee))?;
+    set_field(&obj, "maxNetSolAmount", &int_value(max_net_sol_amount))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Constant-product quote for a buy of `sol_in` lamports on a Pump.fun-style
+/// curve. `newPrice` is lamports per raw token unit after the trade and
+/// `impactBps` is the execution price's deviation from the spot price.
+#[wasm_bindgen(js_name = "computePriceImpact")]
+pub fn compute_price_impact(
+    virtual_sol: u64,
+    virtual_token: u64,
+    sol_in: u64,
+) -> Result<JsValue, JsValue> {
+    if virtual_sol == 0 || virtual_token == 0 {
+        return Err(JsValue::from_str("Reserves must be non-zero"));
+    }
+    let (vs, vt, dx) = (virtual_sol as u128, virtual_token as u128, sol_in as u128);
+
+    // dy = y * dx / (x + dx); u128 cannot overflow for u64 inputs
+    let tokens_out = vt * dx / (vs + dx);
+    let new_sol = vs + dx;
+    let new_token = vt - tokens_out;
+    let new_price = new_sol as f64 / new_token as f64;
+    // 1 - (dy / dx) / (y / x) == dx / (x + dx)
+    let impact_bps = dx * 10_000 / (vs + dx);
+
+    let obj = Object::new();
+    set_field(&obj, "tokensOut", &int_value(tokens_out as u64))?;
+    set_field(&obj, "newPrice", &JsValue::from_f64(new_price))?;
+    set_field(&obj, "impactBps", &JsValue::from_f64(impact_bps as f64))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Largest integer a JS number holds exactly (`Number.MAX_SAFE_INTEGER`).
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Read a u64 field written by `int_value`: a BigInt, a decimal string, or a
+/// safe-integer number.
+fn u64_field(obj: &JsValue, key: &str) -> Result<u64, JsValue> {
+    let v = Reflect::get(obj, &key.into())?;
+    let parsed = if let Some(b) = v.dyn_ref::<BigInt>() {
+        u64::try_from(b.clone()).ok()
+    } else if let Some(s) = v.as_string() {
+        s.parse().ok()
+    } else {
+        v.as_f64()
+            .filter(|n| n.fract() == 0.0 && *n >= 0.0 && *n <= MAX_SAFE_INTEGER)
+            .map(|n| n as u64)
+    };
+    parsed.ok_or_else(|| {
+        JsValue::from_str(&format!(
+            "{} must be a u64 (BigInt, string or integer)",
+            key
+        ))
+    })
+}
+
+/// Difference between two `parsePumpFunCurveState` snapshots. `deltaSol` and
+/// `deltaToken` are `after - before` on the virtual reserves, exact and
+/// signed; `priceBefore` / `priceAfter` are lamports per raw token unit.
+#[wasm_bindgen(js_name = "diffCurveState")]
+pub fn diff_curve_state(before: JsValue, after: JsValue) -> Result<JsValue, JsValue> {
+    let reserves = |state: &JsValue| -> Result<(u64, u64), JsValue> {
+        let sol = u64_field(state, "virtual_sol_reserves")?;
+        let token = u64_field(state, "virtual_token_reserves")?;
+        Ok((sol, token))
+    };
+    let (sol_before, token_before) = reserves(&before)?;
+    let (sol_after, token_after) = reserves(&after)?;
+    let price = |sol: u64, token: u64| {
+        if token == 0 {
+            JsValue::NULL
+        } else {
+            JsValue::from_f64(sol as f64 / token as f64)
+        }
+    };
+
+    let obj = Object::new();
+    set_field(
+        &obj,
+        "deltaSol",
+        &int_value(sol_after as i128 - sol_before as i128),
+    )?;
+    set_field(
+        &obj,
+        "deltaToken",
+        &int_value(token_after as i128 - token_before as i128),
+    )?;
+    set_field(&obj, "priceBefore", &price(sol_before, token_before))?;
+    set_field(&obj, "priceAfter", &price(sol_after, token_after))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// USD market cap of a Pump.fun-style token at the curve's spot price, as a
+/// decimal string with 2 places. Spot price is `virtual_sol / virtual_token`
+/// (lamports per raw unit) and the cap is that times `token_total_supply`,
+/// computed exactly in u128 before the USD conversion. Zero SOL reserves give
+/// `"0.00"`.
+///
+/// `decimals` is not used: reserves and supply are both raw units of the same
+/// mint, so its `10^decimals` cancels out. It is accepted only to keep the
+/// argument list stable for callers and any value is allowed.
+#[wasm_bindgen(js_name = "computeMarketCap")]
+pub fn compute_market_cap(
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+    token_total_supply: u64,
+    decimals: u8,
+    sol_price_usd: f64,
+) -> Result<String, JsValue> {
+    if virtual_token_reserves == 0 {
+        return Err(JsValue::from_str("Token reserves must be non-zero"));
+    }
+    if !sol_price_usd.is_finite() || sol_price_usd < 0.0 {
+        return Err(JsValue::from_str("SOL price must be a non-negative number"));
+    }
+    let _ = decimals;
+    // price_ui = (sol / 1e9) / (token / 10^d) and supply_ui = supply / 10^d,
+    // so the 10^d terms cancel: cap = sol * supply / token lamports
+    let cap_lamports =
+        virtual_sol_reserves as u128 * token_total_supply as u128 / virtual_token_reserves as u128;
+    let cap_usd = cap_lamports as f64 / 1e9 * sol_price_usd;
+
+    Ok(format!("{:.2}", cap_usd))
+}
+
+/// Expected output of a Raydium Launchpad trade of `amount_in`, rounded down
+/// like the program. `direction` is `"buy"` (quote in, base out) or `"sell"`
+/// (base in, quote out). `curve_type` follows `CurveParams`:
+/// - 0 Constant: constant product over the virtual reserves.
+/// - 1 Fixed: fixed price `virtual_quote / virtual_base`.
+/// - 2 Linear: price `a * supply`; pass the base sold so far as `virtual_base`
+///   and the Q64.64 slope `a` as `virtual_quote`.
+#[wasm_bindgen(js_name = "computeLaunchpadQuote")]
+pub fn compute_launchpad_quote(
+    curve_type: u8,
+    virtual_base: u64,
+    virtual_quote: u64,
+    amount_in: u64,
+    direction: &str,
+) -> Result<JsValue, JsValue> {
+    let buy = match direction {
+        "buy" => true,
+        "sell" => false,
+        _ => return Err(JsValue::from_str("direction must be \"buy\" or \"sell\"")),
+    };
+    let (base, quote, dx) = (
+        virtual_base as u128,
+        virtual_quote as u128,
+        amount_in as u128,
+    );
+    let overflow = || JsValue::from_str("Quote overflows u128");
+
+    let out = match curve_type {
+        0 => {
+            // dy = y * dx / (x + dx); u128 cannot overflow for u64 inputs
+            let (x, y) = if buy { (quote, base) } else { (base, quote) };
+            if x + dx == 0 {
+                return Err(JsValue::from_str("Reserves must be non-zero"));
+            }
+            y * dx / (x + dx)
+        }
+        1 => {
+            if base == 0 || quote == 0 {
+                return Err(JsValue::from_str("Reserves must be non-zero"));
+            }
+            if buy {
+                dx * base / quote
+            } else {
+                dx * quote / base
+            }
+        }
+        2 => {
+            if quote == 0 {
+                return Err(JsValue::from_str("Slope must be non-zero"));
+            }
+            // Area under a * x between the old and new supply
+            let s = base;
+            if buy {
+                // s' = sqrt(s^2 + 2 * dx / a), with a = quote / 2^64
+                let step = dx.checked_mul(1 << 65).ok_or_else(overflow)? / quote;
+                let s_new = (s * s).checked_add(step).ok_or_else(overflow)?.isqrt();
+                s_new - s
+            } else {
+                let s_new = s
+                    .checked_sub(dx)
+                    .ok_or_else(|| JsValue::from_str("Sell exceeds supply"))?;
+                let area = (s - s_new).checked_mul(s + s_new).ok_or_else(overflow)?;
+                area.checked_mul(quote).ok_or_else(overflow)? >> 65
+            }
+        }
+        _ => return Err(JsValue::from_str("Unknown curve type")),
+    };
+
+    let out = u64::try_from(out).map_err(|_| JsValue::from_str("Quote exceeds u64"))?;
+    Ok(int_value(out))
+}
+
+/// WASM-exported parser for Raydium CPMM `initialize` (pool creation after migration).
+/// Shares the `global:initialize` discriminator with Raydium Launchpad.
+#[wasm_bindgen(js_name = "parseRaydiumCpmmInitialize")]
+pub fn parse_raydium_cpmm_initialize(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(ix_data, &D_RAY_INIT)?;
+    let mut off = 0;
+
+    let init_amount_0 = read_u64(buf, &mut off)?;
+    let init_amount_1 = read_u64(buf, &mut off)?;
+    let open_time = read_u64(buf, &mut off)?;
+
+    // Indices per CPMM IDL:
+    // 0 = creator, 3 = pool_state, 4 = token_0_mint, 5 = token_1_mint, 6 = lp_mint
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "initAmount0", &int_value(init_amount_0))?;
+    set_field(&obj, "initAmount1", &int_value(init_amount_1))?;
+    set_field(&obj, "openTime", &int_value(open_time))?;
+    set_field(&obj, "creator", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "poolState", &JsValue::from_str(&get(3)?))?;
+    set_field(&obj, "token0Mint", &JsValue::from_str(&get(4)?))?;
+    set_field(&obj, "token1Mint", &JsValue::from_str(&get(5)?))?;
+    set_field(&obj, "lpMint", &JsValue::from_str(&get(6)?))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Shared decoder for Raydium CPMM `swap_base_input` / `swap_base_output`.
+/// Both take two u64 args; `swap_base_input` fixes the input side
+/// (`amount_in`, `minimum_amount_out`), `swap_base_output` the output side
+/// (`max_amount_in`, `amount_out`). CPMM pools have no base/quote, so `side`
+/// is `"buy"` when the swap spends wrapped SOL and `"sell"` otherwise.
+fn parse_cpmm_swap(
+    ix_data: &[u8],
+    accounts: JsValue,
+    discriminator: &[u8; 8],
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(ix_data, discriminator)?;
+    let mut off = 0;
+    // Both layouts put the input-side amount first
+    let amount_in = read_u64(buf, &mut off)?;
+    let amount_out = read_u64(buf, &mut off)?;
+
+    // Indices per CPMM IDL:
+    // 0 = payer, 3 = pool_state, 10 = input_token_mint, 11 = output_token_mint
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+    let input_mint = get(10)?;
+    let side = if input_mint == MINT_WSOL { "buy" } else { "sell" };
+
+    let obj = Object::new();
+    set_field(&obj, "amountIn", &int_value(amount_in))?;
+    set_field(&obj, "amountOut", &int_value(amount_out))?;
+    set_field(&obj, "payer", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "poolState", &JsValue::from_str(&get(3)?))?;
+    set_field(&obj, "inputMint", &JsValue::from_str(&input_mint))?;
+    set_field(&obj, "outputMint", &JsValue::from_str(&get(11)?))?;
+    if discriminator == &D_CPMM_SWAP_BASE_INPUT {
+        // Exact input, bounded by the least output accepted
+        set_trade_limits(&obj, side, amount_in, amount_out, "min_out")?;
+    } else {
+        // Exact output, bounded by the most input spent
+        set_trade_limits(&obj, side, amount_out, amount_in, "max_in")?;
+    }
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for Raydium CPMM `swap_base_input` (exact input,
+/// `amountOut` is the minimum accepted).
+#[wasm_bindgen(js_name = "parseRaydiumCpmmSwapBaseInput")]
+pub fn parse_raydium_cpmm_swap_base_input(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    parse_cpmm_swap(ix_data, accounts, &D_CPMM_SWAP_BASE_INPUT)
+}
+
+/// WASM-exported parser for Raydium CPMM `swap_base_output` (exact output,
+/// `amountIn` is the maximum paid).
+#[wasm_bindgen(js_name = "parseRaydiumCpmmSwapBaseOutput")]
+pub fn parse_raydium_cpmm_swap_base_output(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    parse_cpmm_swap(ix_data, accounts, &D_CPMM_SWAP_BASE_OUTPUT)
+}
+
+/// WASM-exported parser for Raydium AMM V4 `initialize2` (1-byte tag, not Anchor)
+#[wasm_bindgen(js_name = "parseRaydiumAmmV4Initialize2")]
+pub fn parse_raydium_amm_v4_initialize2(
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_n(ix_data, 1)?;
+    if ix_data[0] != TAG_AMM_V4_INITIALIZE2 {
+        return Err(JsValue::from_str("not an AMM V4 initialize2 ix"));
+    }
+    let mut off = 0;
+
+    let nonce = read_u8(buf, &mut off)?;
+    let open_time = read_u64(buf, &mut off)?;
+    let init_pc_amount = read_u64(buf, &mut off)?;
+    let init_coin_amount = read_u64(buf, &mut off)?;
+
+    // Account order of the AMM V4 program:
+    // 4 = amm, 7 = lp_mint, 8 = coin_mint, 9 = pc_mint, 17 = user_wallet
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "nonce", &JsValue::from_f64(nonce as f64))?;
+    set_field(&obj, "openTime", &int_value(open_time))?;
+    set_field(&obj, "initPcAmount", &int_value(init_pc_amount))?;
+    set_field(&obj, "initCoinAmount", &int_value(init_coin_amount))?;
+    set_field(&obj, "ammId", &JsValue::from_str(&get(4)?))?;
+    set_field(&obj, "lpMint", &JsValue::from_str(&get(7)?))?;
+    set_field(&obj, "coinMint", &JsValue::from_str(&get(8)?))?;
+    set_field(&obj, "pcMint", &JsValue::from_str(&get(9)?))?;
+    set_field(&obj, "creator", &JsValue::from_str(&get(17)?))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for SPL Memo instructions, returning `{ memo }`. Memo
+/// data is bare text with no discriminator; invalid UTF-8 is replaced with
+/// U+FFFD rather than failing.
+#[wasm_bindgen(js_name = "parseSplMemo")]
+pub fn parse_spl_memo(data: &[u8]) -> Result<JsValue, JsValue> {
+    let obj = Object::new();
+    set_field(
+        &obj,
+        "memo",
+        &JsValue::from_str(&String::from_utf8_lossy(data)),
+    )?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the Associated Token Account program's `Create` /
+/// `CreateIdempotent`. Their data is empty or a single tag byte, so
+/// everything comes from the accounts.
+#[wasm_bindgen(js_name = "parseCreateAssociatedTokenAccount")]
+pub fn parse_create_associated_token_account(accounts: JsValue) -> Result<JsValue, JsValue> {
+    // Fixed order: 0 = funder, 1 = associated account, 2 = wallet (owner),
+    // 3 = mint, then the system and token programs
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "funder", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "owner", &JsValue::from_str(&get(2)?))?;
+    set_field(&obj, "mint", &JsValue::from_str(&get(3)?))?;
+    set_field(&obj, "ata", &JsValue::from_str(&get(1)?))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for SPL Token / Token-2022 `TransferChecked` (tag 12)
+/// and Token-2022 `TransferCheckedWithFee` (tags 26, 1). `fee` is the
+/// transfer fee withheld from `amount`, or null for the plain variant.
+#[wasm_bindgen(js_name = "parseToken2022TransferChecked")]
+pub fn parse_token_2022_transfer_checked(data: &[u8]) -> Result<JsValue, JsValue> {
+    let (buf, with_fee) = match data {
+        [TAG_TOKEN_TRANSFER_CHECKED, rest @ ..] => (rest, false),
+        [TAG_TOKEN_TRANSFER_FEE_EXTENSION, TAG_TRANSFER_CHECKED_WITH_FEE, rest @ ..] => {
+            (rest, true)
+        }
+        _ => return Err(JsValue::from_str("not a TransferChecked ix")),
+    };
+    let mut off = 0;
+
+    let amount = read_u64(buf, &mut off)?;
+    let decimals = read_u8(buf, &mut off)?;
+    let fee = if with_fee {
+        int_value(read_u64(buf, &mut off)?)
+    } else {
+        JsValue::NULL
+    };
+
+    let obj = Object::new();
+    set_field(&obj, "amount", &int_value(amount))?;
+    set_field(&obj, "decimals", &JsValue::from_f64(decimals as f64))?;
+    set_field(&obj, "fee", &fee)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// A known graduation instruction and where its destination pool sits.
+struct MigrationRoute {
+    program_id: &'static str,
+    discriminator: [u8; 8],
+    destination: &'static str,
+    pool_index: u32,
+    mint_index: u32,
+}
+
+#[rustfmt::skip]
+const MIGRATION_ROUTES: &[MigrationRoute] = &[
+    MigrationRoute { program_id: PROGRAM_PUMP_FUN, discriminator: D_PUMP_MIGRATE, destination: "pumpswap", pool_index: 9, mint_index: 2 },
+    MigrationRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: D_RAY_MIGRATE_TO_AMM, destination: "raydium_amm_v4", pool_index: 13, mint_index: 1 },
+    MigrationRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: D_RAY_MIGRATE_TO_CPSWAP, destination: "raydium_cpmm", pool_index: 5, mint_index: 1 },
+    MigrationRoute { program_id: PROGRAM_METEORA_DBC, discriminator: D_METEORA_MIGRATE_DAMM, destination: "meteora_damm", pool_index: 4, mint_index: 7 },
+    MigrationRoute { program_id: PROGRAM_METEORA_DBC, discriminator: D_METEORA_MIGRATE_DAMM_V2, destination: "meteora_damm_v2", pool_index: 4, mint_index: 13 },
+    MigrationRoute { program_id: PROGRAM_BOOP, discriminator: D_BOOP_CREATE_RAYDIUM_POOL, destination: "raydium_cpmm", pool_index: 3, mint_index: 4 },
+];
+
+/// Classify a graduation instruction by program id and discriminator and
+/// return `{ destination, pool, mint }`, e.g. `destination: "pumpswap"`.
+#[wasm_bindgen(js_name = "detectMigration")]
+pub fn detect_migration(
+    program_id: &str,
+    ix_data: &[u8],
+    accounts: JsValue,
+) -> Result<JsValue, JsValue> {
+    let head = ix_data
+        .get(..8)
+        .ok_or_else(|| JsValue::from_str("Data too short"))?;
+    let route = MIGRATION_ROUTES
+        .iter()
+        .find(|r| r.program_id == program_id && r.discriminator[..] == *head)
+        .ok_or_else(|| JsValue::from_str("not a known migration ix"))?;
+
+    let accs = accounts_array(&accounts)?;
+
+    let obj = Object::new();
+    set_field(&obj, "destination", &JsValue::from_str(route.destination))?;
+    set_field(
+        &obj,
+        "pool",
+        &JsValue::from_str(&account_at(&accs, route.pool_index)?),
+    )?;
+    set_field(
+        &obj,
+        "mint",
+        &JsValue::from_str(&account_at(&accs, route.mint_index)?),
+    )?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Where a platform's own pool / bonding-curve account sits in an
+/// instruction's accounts. An empty discriminator is the program default,
+/// which covers its trade instructions.
+struct PoolAccountRoute {
+    program_id: &'static str,
+    discriminator: &'static [u8],
+    index: u32,
+}
+
+// Specific instructions first: the first matching entry wins
+#[rustfmt::skip]
+const POOL_ACCOUNT_ROUTES: &[PoolAccountRoute] = &[
+    PoolAccountRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_CREATE, index: 2 },
+    PoolAccountRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_SET_CREATOR, index: 4 },
+    PoolAccountRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_ADMIN_SET_CREATOR, index: 3 },
+    PoolAccountRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &[], index: 3 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_INIT, index: 5 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_INIT_V2, index: 5 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_INIT_T22, index: 5 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_CREATE_VESTING, index: 2 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_CLAIM_VESTED, index: 2 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_MIGRATE_TO_AMM, index: 23 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_MIGRATE_TO_CPSWAP, index: 17 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &[], index: 4 },
+    PoolAccountRoute { program_id: PROGRAM_MOONSHOT, discriminator: &[], index: 2 },
+    PoolAccountRoute { program_id: PROGRAM_BOOP, discriminator: &D_BOOP_CREATE_RAYDIUM_POOL, index: 8 },
+    PoolAccountRoute { program_id: PROGRAM_BOOP, discriminator: &[], index: 1 },
+    PoolAccountRoute { program_id: PROGRAM_METEORA_DBC, discriminator: &D_METEORA_INIT_SPL, index: 5 },
+    PoolAccountRoute { program_id: PROGRAM_METEORA_DBC, discriminator: &D_METEORA_INIT_T22, index: 5 },
+    PoolAccountRoute { program_id: PROGRAM_METEORA_DBC, discriminator: &[], index: 2 },
+    PoolAccountRoute { program_id: PROGRAM_PUMP_SWAP, discriminator: &[], index: 0 },
+    PoolAccountRoute { program_id: PROGRAM_RAYDIUM_CPMM, discriminator: &[], index: 3 },
+];
+
+/// The bonding-curve / pool account of an instruction on a supported
+/// launchpad or AMM. Pass the instruction `data` when it isn't a trade, since
+/// creates and migrations put the pool elsewhere; without it the trade index
+/// is used.
+#[wasm_bindgen(js_name = "extractPoolAccount")]
+pub fn extract_pool_account(
+    program_id: &str,
+    accounts: JsValue,
+    data: Option<Vec<u8>>,
+) -> Result<String, JsValue> {
+    let data = data.unwrap_or_default();
+    let route = POOL_ACCOUNT_ROUTES
+        .iter()
+        .find(|r| r.program_id == program_id && data.starts_with(r.discriminator))
+        .ok_or_else(|| JsValue::from_str("unsupported program"))?;
+
+    let accs = accounts_array(&accounts)?;
+    account_at(&accs, route.index)
+}
+
+/// WASM-exported parser for PumpSwap `create_pool`, which seeds the pool's
+/// initial reserves; for Pump.fun graduations this completes the migration.
+/// `coinCreator` is null for the older args layout without it.
+#[wasm_bindgen(js_name = "parsePumpSwapCreatePool")]
+pub fn parse_pump_swap_create_pool(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(ix_data, &D_PUMP_SWAP_CREATE_POOL)?;
+    let mut off = 0;
+
+    let index = read_u16(buf, &mut off)?;
+    let base_amount_in = read_u64(buf, &mut off)?;
+    let quote_amount_in = read_u64(buf, &mut off)?;
+    let coin_creator = if checked_end(buf, off, 32).is_some() {
+        read_pubkey_js(buf, &mut off)?
+    } else {
+        JsValue::NULL
+    };
+
+    // Indices per IDL:
+    // 0 = pool, 2 = creator, 3 = base_mint, 4 = quote_mint, 5 = lp_mint
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "index", &JsValue::from_f64(index as f64))?;
+    set_field(&obj, "baseAmountIn", &int_value(base_amount_in))?;
+    set_field(&obj, "quoteAmountIn", &int_value(quote_amount_in))?;
+    set_field(&obj, "coinCreator", &coin_creator)?;
+    set_field(&obj, "pool", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "creator", &JsValue::from_str(&get(2)?))?;
+    set_field(&obj, "baseMint", &JsValue::from_str(&get(3)?))?;
+    set_field(&obj, "quoteMint", &JsValue::from_str(&get(4)?))?;
+    set_field(&obj, "lpMint", &JsValue::from_str(&get(5)?))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Shared decoder for PumpSwap `deposit` / `withdraw`: the LP amount and the
+/// two per-side bounds, named by `base_key` / `quote_key`. Both instructions
+/// share the account layout.
+fn parse_pump_swap_liquidity(
+    ix_data: &[u8],
+    accounts: JsValue,
+    discriminator: &[u8; 8],
+    base_key: &str,
+    quote_key: &str,
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(ix_data, discriminator)?;
+    let mut off = 0;
+
+    let lp_token_amount = read_u64(buf, &mut off)?;
+    let base_limit = read_u64(buf, &mut off)?;
+    let quote_limit = read_u64(buf, &mut off)?;
+
+    // Indices per IDL:
+    // 0 = pool, 2 = user, 3 = base_mint, 4 = quote_mint, 5 = lp_mint
+    let accs = accounts_array(&accounts)?;
+    let get = |i: u32| account_at(&accs, i);
+
+    let obj = Object::new();
+    set_field(&obj, "lpTokenAmount", &int_value(lp_token_amount))?;
+    set_field(&obj, base_key, &int_value(base_limit))?;
+    set_field(&obj, quote_key, &int_value(quote_limit))?;
+    set_field(&obj, "pool", &JsValue::from_str(&get(0)?))?;
+    set_field(&obj, "user", &JsValue::from_str(&get(2)?))?;
+    set_field(&obj, "baseMint", &JsValue::from_str(&get(3)?))?;
+    set_field(&obj, "quoteMint", &JsValue::from_str(&get(4)?))?;
+    set_field(&obj, "lpMint", &JsValue::from_str(&get(5)?))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for PumpSwap `deposit`: mint `lpTokenAmount` LP tokens
+/// for at most `maxBaseAmountIn` / `maxQuoteAmountIn`.
+#[wasm_bindgen(js_name = "parsePumpSwapDeposit")]
+pub fn parse_pump_swap_deposit(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    parse_pump_swap_liquidity(
+        ix_data,
+        accounts,
+        &D_PUMP_SWAP_DEPOSIT,
+        "maxBaseAmountIn",
+        "maxQuoteAmountIn",
+    )
+}
+
+/// WASM-exported parser for PumpSwap `withdraw`: burn `lpTokenAmount` LP
+/// tokens for at least `minBaseAmountOut` / `minQuoteAmountOut`.
+#[wasm_bindgen(js_name = "parsePumpSwapWithdraw")]
+pub fn parse_pump_swap_withdraw(ix_data: &[u8], accounts: JsValue) -> Result<JsValue, JsValue> {
+    parse_pump_swap_liquidity(
+        ix_data,
+        accounts,
+        &D_PUMP_SWAP_WITHDRAW,
+        "minBaseAmountOut",
+        "minQuoteAmountOut",
+    )
+}
+
+/// WASM-exported parser for a PumpSwap `Pool` account. Reserves are held in
+/// the pool's token accounts; pass their data as `base_vault_data` /
+/// `quote_vault_data` to include `baseReserve` / `quoteReserve`, or empty
+/// arrays to skip them. Fee rates live in the PumpSwap GlobalConfig.
+#[wasm_bindgen(js_name = "parsePumpSwapPoolState")]
+pub fn parse_pump_swap_pool_state(
+    data: &[u8],
+    base_vault_data: &[u8],
+    quote_vault_data: &[u8],
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_PUMP_SWAP_POOL)?;
+    let mut off = 0;
+
+    let pool_bump = read_u8(buf, &mut off)?;
+    let index = read_u16(buf, &mut off)?;
+    let creator = read_pubkey_js(buf, &mut off)?;
+    let base_mint = read_pubkey_js(buf, &mut off)?;
+    let quote_mint = read_pubkey_js(buf, &mut off)?;
+    let lp_mint = read_pubkey_js(buf, &mut off)?;
+    let pool_base_token_account = read_pubkey_js(buf, &mut off)?;
+    let pool_quote_token_account = read_pubkey_js(buf, &mut off)?;
+    let lp_supply = read_u64(buf, &mut off)?;
+    // coin_creator was appended for creator fees; older pools end here
+    let coin_creator = if checked_end(buf, off, 32).is_some() {
+        read_pubkey_js(buf, &mut off)?
+    } else {
+        JsValue::NULL
+    };
+
+    let obj = Object::new();
+    set_field(&obj, "poolBump", &JsValue::from_f64(pool_bump as f64))?;
+    set_field(&obj, "index", &JsValue::from_f64(index as f64))?;
+    set_field(&obj, "creator", &creator)?;
+    set_field(&obj, "baseMint", &base_mint)?;
+    set_field(&obj, "quoteMint", &quote_mint)?;
+    set_field(&obj, "lpMint", &lp_mint)?;
+    set_field(&obj, "poolBaseTokenAccount", &pool_base_token_account)?;
+    set_field(&obj, "poolQuoteTokenAccount", &pool_quote_token_account)?;
+    set_field(&obj, "lpSupply", &int_value(lp_supply))?;
+    set_field(&obj, "coinCreator", &coin_creator)?;
+
+    if !base_vault_data.is_empty() {
+        let base_reserve = token_account_amount(base_vault_data)?;
+        set_field(&obj, "baseReserve", &int_value(base_reserve))?;
+    }
+    if !quote_vault_data.is_empty() {
+        let quote_reserve = token_account_amount(quote_vault_data)?;
+        set_field(&obj, "quoteReserve", &int_value(quote_reserve))?;
+    }
+
+    Ok(JsValue::from(obj))
+}
+
+/// Jupiter v6 route instructions whose top-level amounts `parseJupiterRoute` reads.
+const JUPITER_ROUTES: &[(&str, [u8; 8])] = &[
+    ("route", D_JUP_ROUTE),
+    ("shared_accounts_route", D_JUP_SHARED_ACCOUNTS_ROUTE),
+    ("exact_out_route", D_JUP_EXACT_OUT_ROUTE),
+];
+
+/// Whether `data` is a Jupiter v6 route instruction (`route`,
+/// `shared_accounts_route` or `exact_out_route`) for `program_id`.
+#[wasm_bindgen(js_name = "isJupiterRoute")]
+pub fn is_jupiter_route(program_id: &str, data: &[u8]) -> bool {
+    program_id == PROGRAM_JUPITER
+        && data
+            .get(..8)
+            .is_some_and(|head| JUPITER_ROUTES.iter().any(|(_, d)| d[..] == *head))
+}
+
+/// WASM-exported parser for the top-level args of a Jupiter v6 route
+/// instruction, without decoding the nested route plan. The plan is a
+/// variable-length vec, but the amounts follow it as a fixed 19-byte tail:
+/// two u64s, `slippage_bps: u16`, `platform_fee_bps: u8`. For
+/// `exact_out_route` the u64s are `outAmount` / `quotedInAmount`.
+#[wasm_bindgen(js_name = "parseJupiterRoute")]
+pub fn parse_jupiter_route(data: &[u8]) -> Result<JsValue, JsValue> {
+    let head = data
+        .get(..8)
+        .ok_or_else(|| JsValue::from_str("Data too short"))?;
+    let (instruction, _) = JUPITER_ROUTES
+        .iter()
+        .find(|(_, d)| d[..] == *head)
+        .ok_or_else(|| JsValue::from_str("not a Jupiter route ix"))?;
+
+    const TAIL: usize = 8 + 8 + 2 + 1;
+    let buf = &data[8..];
+    let mut off = buf
+        .len()
+        .checked_sub(TAIL)
+        .ok_or_else(|| JsValue::from_str("Route args exceed buffer"))?;
+    let first = read_u64(buf, &mut off)?;
+    let second = read_u64(buf, &mut off)?;
+    let slippage_bps = read_u16(buf, &mut off)?;
+    let platform_fee_bps = read_u8(buf, &mut off)?;
+
+    let (first_key, second_key) = if *instruction == "exact_out_route" {
+        ("outAmount", "quotedInAmount")
+    } else {
+        ("inAmount", "quotedOutAmount")
+    };
+
+    let obj = Object::new();
+    set_field(&obj, "instruction", &JsValue::from_str(instruction))?;
+    set_field(&obj, first_key, &int_value(first))?;
+    set_field(&obj, second_key, &int_value(second))?;
+    set_field(&obj, "slippageBps", &JsValue::from_f64(slippage_bps as f64))?;
+    set_field(
+        &obj,
+        "platformFeeBps",
+        &JsValue::from_f64(platform_fee_bps as f64),
+    )?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for a Meteora DAMM v1 `Pool` account, where DBC pools
+/// graduate via `migrate_meteora_damm`. The pool holds no token balances; it
+/// owns LP shares of two Meteora vaults. Pass the `a_vault_lp` / `b_vault_lp`
+/// token accounts as `a_vault_lp_data` / `b_vault_lp_data` to include
+/// `aVaultLpAmount` / `bVaultLpAmount`, or empty arrays to skip them. Fees are
+/// fractions `numerator / denominator`.
+#[wasm_bindgen(js_name = "parseMeteoraDammPoolState")]
+pub fn parse_meteora_damm_pool_state(
+    data: &[u8],
+    a_vault_lp_data: &[u8],
+    b_vault_lp_data: &[u8],
+) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_ACC_METEORA_DAMM_POOL)?;
+    let mut off = 0;
+
+    let lp_mint = read_pubkey_js(buf, &mut off)?;
+    let token_a_mint = read_pubkey_js(buf, &mut off)?;
+    let token_b_mint = read_pubkey_js(buf, &mut off)?;
+    let a_vault = read_pubkey_js(buf, &mut off)?;
+    let b_vault = read_pubkey_js(buf, &mut off)?;
+    let a_vault_lp = read_pubkey_js(buf, &mut off)?;
+    let b_vault_lp = read_pubkey_js(buf, &mut off)?;
+    skip(buf, &mut off, 1)?; // a_vault_lp_bump
+    let enabled = read_u8(buf, &mut off)? != 0;
+    // protocol_token_a_fee, protocol_token_b_fee, fee_last_updated_at, padding0
+    skip(buf, &mut off, 32 * 2 + 8 + 24)?;
+    let [trade_fee_numerator, trade_fee_denominator, protocol_trade_fee_numerator, protocol_trade_fee_denominator] =
+        read_u64_array::<4>(buf, &mut off)?;
+    let pool_type = read_u8(buf, &mut off)?;
+
+    let obj = Object::new();
+    set_field(&obj, "lpMint", &lp_mint)?;
+    set_field(&obj, "tokenAMint", &token_a_mint)?;
+    set_field(&obj, "tokenBMint", &token_b_mint)?;
+    set_field(&obj, "aVault", &a_vault)?;
+    set_field(&obj, "bVault", &b_vault)?;
+    set_field(&obj, "aVaultLp", &a_vault_lp)?;
+    set_field(&obj, "bVaultLp", &b_vault_lp)?;
+    set_field(&obj, "enabled", &JsValue::from_bool(enabled))?;
+    set_field(&obj, "tradeFeeNumerator", &int_value(trade_fee_numerator))?;
+    set_field(
+        &obj,
+        "tradeFeeDenominator",
+        &int_value(trade_fee_denominator),
+    )?;
+    set_field(
+        &obj,
+        "protocolTradeFeeNumerator",
+        &int_value(protocol_trade_fee_numerator),
+    )?;
+    set_field(
+        &obj,
+        "protocolTradeFeeDenominator",
+        &int_value(protocol_trade_fee_denominator),
+    )?;
+    // 0 = Permissioned, 1 = Permissionless
+    set_field(&obj, "poolType", &JsValue::from_f64(pool_type as f64))?;
+
+    if !a_vault_lp_data.is_empty() {
+        let amount = token_account_amount(a_vault_lp_data)?;
+        set_field(&obj, "aVaultLpAmount", &int_value(amount))?;
+    }
+    if !b_vault_lp_data.is_empty() {
+        let amount = token_account_amount(b_vault_lp_data)?;
+        set_field(&obj, "bVaultLpAmount", &int_value(amount))?;
+    }
+
+    Ok(JsValue::from(obj))
+}
+
+/// WASM-exported parser for the Meteora DAMM v1 `Swap` event from
+/// `Program data:` logs. The event has no pool or direction; take those from
+/// the enclosing swap instruction. `tradeFee` is the LP fee, charged on the
+/// input side like `protocolFee` and `hostFee` (the referrer's cut).
+#[wasm_bindgen(js_name = "parseMeteoraDammSwapEvent")]
+pub fn parse_meteora_damm_swap_event(data: &[u8]) -> Result<JsValue, JsValue> {
+    let buf = payload_checked(data, &D_METEORA_DAMM_SWAP_EVENT)?;
+    let mut off = 0;
+
+    let in_amount = read_u64(buf, &mut off)?;
+    let out_amount = read_u64(buf, &mut off)?;
+    let trade_fee = read_u64(buf, &mut off)?;
+    let protocol_fee = read_u64(buf, &mut off)?;
+    let host_fee = read_u64(buf, &mut off)?;
+
+    let obj = Object::new();
+    set_field(&obj, "inAmount", &int_value(in_amount))?;
+    set_field(&obj, "outAmount", &int_value(out_amount))?;
+    set_field(&obj, "tradeFee", &int_value(trade_fee))?;
+    set_field(&obj, "protocolFee", &int_value(protocol_fee))?;
+    set_field(&obj, "hostFee", &int_value(host_fee))?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// A parser `decode` can route to, keyed by program id and leading bytes.
+struct DecodeRoute {
+    program_id: &'static str,
+    discriminator: &'static [u8],
+    platform: &'static str,
+    instruction: &'static str,
+    parse: fn(&[u8], JsValue) -> Result<JsValue, JsValue>,
+}
+
+#[rustfmt::skip]
+const DECODE_ROUTES: &[DecodeRoute] = &[
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_CREATE, platform: "pump_fun", instruction: "create", parse: |d, _| parse_token_name_symbol(PROGRAM_PUMP_FUN, d) },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_CREATE_EVENT, platform: "pump_fun", instruction: "CreateEvent", parse: |d, _| parse_pump_fun_create(d, None, None) },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_COMPLETE_EVENT, platform: "pump_fun", instruction: "CompleteEvent", parse: |d, _| parse_pump_fun_complete_event(d, None) },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_TRADE_EVENT, platform: "pump_fun", instruction: "TradeEvent", parse: |d, _| parse_pump_fun_trade_event(d, None) },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_SET_PARAMS, platform: "pump_fun", instruction: "set_params", parse: |d, _| parse_pump_fun_set_params(d, None) },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_EXTEND_ACCOUNT, platform: "pump_fun", instruction: "extend_account", parse: |d, a| parse_pump_fun_admin(d, a, None) },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_SET_CREATOR, platform: "pump_fun", instruction: "set_creator", parse: |d, a| parse_pump_fun_admin(d, a, None) },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_ADMIN_SET_CREATOR, platform: "pump_fun", instruction: "admin_set_creator", parse: |d, a| parse_pump_fun_admin(d, a, None) },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_COLLECT_CREATOR_FEE, platform: "pump_fun", instruction: "collect_creator_fee", parse: parse_pump_fun_collect_creator_fee },
+    DecodeRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_PUMP_UPDATE_GLOBAL_AUTHORITY, platform: "pump_fun", instruction: "update_global_authority", parse: |d, a| parse_pump_fun_admin(d, a, None) },
+    DecodeRoute { program_id: PROGRAM_BOOP, discriminator: &D_BOOP_CREATE_TOKEN, platform: "boop", instruction: "create_token", parse: |d, _| parse_boop_create_token(d) },
+    DecodeRoute { program_id: PROGRAM_BOOP, discriminator: &D_BOOP_BUY_TOKEN, platform: "boop", instruction: "buy_token", parse: parse_boop_buy },
+    DecodeRoute { program_id: PROGRAM_BOOP, discriminator: &D_BOOP_SELL_TOKEN, platform: "boop", instruction: "sell_token", parse: parse_boop_sell },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_INIT, platform: "raydium_launchpad", instruction: "initialize", parse: |d, _| parse_raydium_initialize(d) },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_INIT_V2, platform: "raydium_launchpad", instruction: "initialize_v2", parse: |d, _| parse_raydium_initialize(d) },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_INIT_T22, platform: "raydium_launchpad", instruction: "initialize_with_token_2022", parse: |d, _| parse_raydium_initialize(d) },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_BUY_EXACT_IN, platform: "raydium_launchpad", instruction: "buy_exact_in", parse: |d, a| parse_raydium_launchpad_buy_exact_in(d, a, None) },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_SELL_EXACT_IN, platform: "raydium_launchpad", instruction: "sell_exact_in", parse: |d, a| parse_raydium_launchpad_sell_exact_in(d, a, None) },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_CLAIM_VESTED, platform: "raydium_launchpad", instruction: "claim_vested_token", parse: parse_launchpad_claim_vested },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_CREATE_VESTING, platform: "raydium_launchpad", instruction: "create_vesting_account", parse: parse_launchpad_create_vesting },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_MIGRATE_TO_AMM, platform: "raydium_launchpad", instruction: "migrate_to_amm", parse: parse_raydium_launchpad_migrate_to_amm },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_RAY_MIGRATE_TO_CPSWAP, platform: "raydium_launchpad", instruction: "migrate_to_cpswap", parse: parse_raydium_launchpad_migrate_to_cpmm },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_TRADE_EVENT, platform: "raydium_launchpad", instruction: "TradeEvent", parse: |d, _| parse_launchpad_trade_event(d) },
+    DecodeRoute { program_id: PROGRAM_MOONSHOT, discriminator: &D_MOONSHOT_TOKEN_MINT, platform: "moonshot", instruction: "token_mint", parse: |d, _| parse_moonshot_token_mint(d, None) },
+    DecodeRoute { program_id: PROGRAM_MOONSHOT, discriminator: &D_BUY, platform: "moonshot", instruction: "buy", parse: |d, _| parse_moonshot_buy(d) },
+    DecodeRoute { program_id: PROGRAM_MOONSHOT, discriminator: &D_SELL, platform: "moonshot", instruction: "sell", parse: |d, _| parse_moonshot_sell(d) },
+    DecodeRoute { program_id: PROGRAM_MOONSHOT, discriminator: &D_MOONSHOT_MIGRATE_FUNDS, platform: "moonshot", instruction: "migrate_funds", parse: parse_moonshot_migrate },
+    DecodeRoute { program_id: PROGRAM_METEORA_DBC, discriminator: &D_METEORA_INIT_SPL, platform: "meteora_dbc", instruction: "initialize_virtual_pool_with_spl_token", parse: parse_meteora_initialize },
+    DecodeRoute { program_id: PROGRAM_METEORA_DBC, discriminator: &D_METEORA_INIT_T22, platform: "meteora_dbc", instruction: "initialize_virtual_pool_with_token2022", parse: parse_meteora_initialize },
+    DecodeRoute { program_id: PROGRAM_PUMP_SWAP, discriminator: &D_PUMP_SWAP_CREATE_POOL, platform: "pump_swap", instruction: "create_pool", parse: parse_pump_swap_create_pool },
+    DecodeRoute { program_id: PROGRAM_PUMP_SWAP, discriminator: &D_PUMP_SWAP_DEPOSIT, platform: "pump_swap", instruction: "deposit", parse: parse_pump_swap_deposit },
+    DecodeRoute { program_id: PROGRAM_PUMP_SWAP, discriminator: &D_PUMP_SWAP_WITHDRAW, platform: "pump_swap", instruction: "withdraw", parse: parse_pump_swap_withdraw },
+    DecodeRoute { program_id: PROGRAM_METEORA_DAMM, discriminator: &D_METEORA_DAMM_SWAP_EVENT, platform: "meteora_damm", instruction: "Swap", parse: |d, _| parse_meteora_damm_swap_event(d) },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_CPMM, discriminator: &D_RAY_INIT, platform: "raydium_cpmm", instruction: "initialize", parse: parse_raydium_cpmm_initialize },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_CPMM, discriminator: &D_CPMM_SWAP_BASE_INPUT, platform: "raydium_cpmm", instruction: "swap_base_input", parse: parse_raydium_cpmm_swap_base_input },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_CPMM, discriminator: &D_CPMM_SWAP_BASE_OUTPUT, platform: "raydium_cpmm", instruction: "swap_base_output", parse: parse_raydium_cpmm_swap_base_output },
+    DecodeRoute { program_id: PROGRAM_RAYDIUM_AMM_V4, discriminator: &[TAG_AMM_V4_INITIALIZE2], platform: "raydium_amm_v4", instruction: "initialize2", parse: parse_raydium_amm_v4_initialize2 },
+    DecodeRoute { program_id: PROGRAM_JUPITER, discriminator: &D_JUP_ROUTE, platform: "jupiter", instruction: "route", parse: |d, _| parse_jupiter_route(d) },
+    DecodeRoute { program_id: PROGRAM_JUPITER, discriminator: &D_JUP_SHARED_ACCOUNTS_ROUTE, platform: "jupiter", instruction: "shared_accounts_route", parse: |d, _| parse_jupiter_route(d) },
+    DecodeRoute { program_id: PROGRAM_JUPITER, discriminator: &D_JUP_EXACT_OUT_ROUTE, platform: "jupiter", instruction: "exact_out_route", parse: |d, _| parse_jupiter_route(d) },
+    DecodeRoute { program_id: PROGRAM_MEMO, discriminator: &[], platform: "spl_memo", instruction: "memo", parse: |d, _| parse_spl_memo(d) },
+    DecodeRoute { program_id: PROGRAM_MEMO_V1, discriminator: &[], platform: "spl_memo", instruction: "memo", parse: |d, _| parse_spl_memo(d) },
+    DecodeRoute { program_id: PROGRAM_ATA, discriminator: &[TAG_ATA_CREATE], platform: "spl_ata", instruction: "create", parse: |_, a| parse_create_associated_token_account(a) },
+    DecodeRoute { program_id: PROGRAM_ATA, discriminator: &[TAG_ATA_CREATE_IDEMPOTENT], platform: "spl_ata", instruction: "create_idempotent", parse: |_, a| parse_create_associated_token_account(a) },
+    DecodeRoute { program_id: PROGRAM_TOKEN, discriminator: &[TAG_TOKEN_TRANSFER_CHECKED], platform: "spl_token", instruction: "transfer_checked", parse: |d, _| parse_token_2022_transfer_checked(d) },
+    DecodeRoute { program_id: PROGRAM_TOKEN_2022, discriminator: &[TAG_TOKEN_TRANSFER_CHECKED], platform: "spl_token_2022", instruction: "transfer_checked", parse: |d, _| parse_token_2022_transfer_checked(d) },
+    DecodeRoute { program_id: PROGRAM_TOKEN_2022, discriminator: &[TAG_TOKEN_TRANSFER_FEE_EXTENSION, TAG_TRANSFER_CHECKED_WITH_FEE], platform: "spl_token_2022", instruction: "transfer_checked_with_fee", parse: |d, _| parse_token_2022_transfer_checked(d) },
+];
+
+/// `DECODE_ROUTES` indices sorted by (program id, discriminator, index), plus
+/// each program's slice of them and its distinct discriminator lengths.
+struct RouteIndex {
+    order: Vec<usize>,
+    programs: Vec<ProgramRoutes>,
+}
+
+/// One program's run in `RouteIndex::order`.
+struct ProgramRoutes {
+    program_id: &'static str,
+    start: usize,
+    end: usize,
+    /// Discriminators are 0 (memo), 1-2 (SPL Token tags) or 8 bytes, so a
+    /// program has very few distinct lengths to probe.
+    lengths: Vec<usize>,
+}
+
+impl RouteIndex {
+    fn build() -> Self {
+        let route = |i: usize| &DECODE_ROUTES[i];
+        let mut order: Vec<usize> = (0..DECODE_ROUTES.len()).collect();
+        order.sort_by_key(|&i| (route(i).program_id, route(i).discriminator, i));
+
+        let mut programs: Vec<ProgramRoutes> = Vec::new();
+        for (at, &i) in order.iter().enumerate() {
+            match programs.last_mut() {
+                Some(p) if p.program_id == route(i).program_id => p.end = at + 1,
+                _ => programs.push(ProgramRoutes {
+                    program_id: route(i).program_id,
+                    start: at,
+                    end: at + 1,
+                    lengths: Vec::new(),
+                }),
+            }
+        }
+        for p in &mut programs {
+            p.lengths = order[p.start..p.end]
+                .iter()
+                .map(|&i| route(i).discriminator.len())
+                .collect();
+            p.lengths.sort_unstable();
+            p.lengths.dedup();
+        }
+        RouteIndex { order, programs }
+    }
+}
+
+thread_local! {
+    // Built on first use so `decode` can binary-search instead of scanning.
+    static ROUTE_INDEX: OnceCell<RouteIndex> = const { OnceCell::new() };
+}
+
+/// The first `DECODE_ROUTES` entry (in table order) for `program_id` whose
+/// discriminator prefixes `data`, in O(log n) per discriminator length.
+fn find_route(program_id: &str, data: &[u8]) -> Option<&'static DecodeRoute> {
+    ROUTE_INDEX.with(|cell| {
+        let index = cell.get_or_init(RouteIndex::build);
+        let at = index
+            .programs
+            .binary_search_by(|p| p.program_id.cmp(program_id))
+            .ok()?;
+        let program = &index.programs[at];
+        let routes = &index.order[program.start..program.end];
+        let route = |i: &usize| &DECODE_ROUTES[*i];
+
+        program
+            .lengths
+            .iter()
+            .filter_map(|&len| {
+                let head = data.get(..len)?;
+                let at = routes.partition_point(|i| route(i).discriminator < head);
+                routes
+                    .get(at)
+                    .copied()
+                    .filter(|i| route(i).discriminator == head)
+            })
+            .min()
+            .map(|i| &DECODE_ROUTES[i])
+    })
+}
+
+/// An account-state parser keyed by owning program and `account:` discriminator.
+struct AccountRoute {
+    program_id: &'static str,
+    discriminator: &'static [u8; 8],
+    platform: &'static str,
+    account: &'static str,
+    parse: fn(&[u8]) -> Result<JsValue, JsValue>,
+}
+
+#[rustfmt::skip]
+const ACCOUNT_ROUTES: &[AccountRoute] = &[
+    AccountRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_ACC_BONDING_CURVE, platform: "pump_fun", account: "BondingCurve", parse: |d| parse_pump_fun_curve_state_with_creator(d) },
+    AccountRoute { program_id: PROGRAM_PUMP_FUN, discriminator: &D_ACC_PUMP_GLOBAL, platform: "pump_fun", account: "Global", parse: |d| parse_pump_fun_global(d) },
+    AccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_ACC_POOL_STATE, platform: "raydium_launchpad", account: "PoolState", parse: |d| parse_launchpad_pool_state(d, None, None) },
+    AccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_ACC_GLOBAL_CONFIG, platform: "raydium_launchpad", account: "GlobalConfig", parse: |d| parse_launchpad_global_config_full(d) },
+    AccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_ACC_PLATFORM_CONFIG, platform: "raydium_launchpad", account: "PlatformConfig", parse: |d| parse_launchpad_platform_config(d) },
+    AccountRoute { program_id: PROGRAM_RAYDIUM_LAUNCHPAD, discriminator: &D_ACC_VESTING_RECORD, platform: "raydium_launchpad", account: "VestingRecord", parse: |d| parse_launchpad_vesting(d, &[]) },
+    AccountRoute { program_id: PROGRAM_MOONSHOT, discriminator: &D_ACC_MOONSHOT_CONFIG, platform: "moonshot", account: "ConfigAccount", parse: |d| parse_moonshot_config(d) },
+    AccountRoute { program_id: PROGRAM_MOONSHOT, discriminator: &D_ACC_MOONSHOT_CURVE, platform: "moonshot", account: "CurveAccount", parse: |d| parse_moonshot_curve(d) },
+    AccountRoute { program_id: PROGRAM_PUMP_SWAP, discriminator: &D_ACC_PUMP_SWAP_POOL, platform: "pump_swap", account: "Pool", parse: |d| parse_pump_swap_pool_state(d, &[], &[]) },
+    AccountRoute { program_id: PROGRAM_METEORA_DAMM, discriminator: &D_ACC_METEORA_DAMM_POOL, platform: "meteora_damm", account: "Pool", parse: |d| parse_meteora_damm_pool_state(d, &[], &[]) },
+];
+
+fn account_route(program_id: &str, data: &[u8]) -> Option<&'static AccountRoute> {
+    ACCOUNT_ROUTES
+        .iter()
+        .find(|r| r.program_id == program_id && data.starts_with(r.discriminator))
+}
+
+/// Single entry point: route `data` by program id and discriminator to the
+/// matching parser and return `{ platform, instruction, fields }`. Account
+/// and event data are routed by their owning program. `accounts` is only
+/// read by parsers that resolve keys and may be null otherwise.
+/// With `allow_unknown = true`, an unrecognised discriminator of a known
+/// program yields `{ platform, instruction: null, discriminatorHex, dataHex }`
+/// instead of an error; unknown programs fail with `"unsupported program"`
+/// before the data is looked at.
+#[wasm_bindgen(js_name = "decode")]
+pub fn decode(
+    program_id: &str,
+    data: &[u8],
+    accounts: JsValue,
+    allow_unknown: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    if !is_supported_program(program_id) {
+        return Err(JsValue::from_str("unsupported program"));
+    }
+    let Some(route) = find_route(program_id, data) else {
+        if let Some(route) = account_route(program_id, data) {
+            return routed(route.platform, route.account, (route.parse)(data)?);
+        }
+        let known = DECODE_ROUTES.iter().find(|r| r.program_id == program_id);
+        return match known {
+            Some(r) if allow_unknown.unwrap_or(false) => unknown_instruction(r, data),
+            _ => Err(JsValue::from_str(
+                "no parser for this program and discriminator",
+            )),
+        };
+    };
+    routed(
+        route.platform,
+        route.instruction,
+        (route.parse)(data, accounts)?,
+    )
+}
+
+/// Whether `decode` has any instruction, event or account parser for
+/// `program_id`, so generic indexers can skip other programs cheaply.
+#[wasm_bindgen(js_name = "isSupportedProgram")]
+pub fn is_supported_program(program_id: &str) -> bool {
+    DECODE_ROUTES.iter().any(|r| r.program_id == program_id)
+        || ACCOUNT_ROUTES.iter().any(|r| r.program_id == program_id)
+}
+
+/// `decode` output: `{ platform, instruction, fields }`.
+fn routed(platform: &str, instruction: &str, fields: JsValue) -> Result<JsValue, JsValue> {
+    let obj = Object::new();
+    set_field(&obj, "platform", &JsValue::from_str(platform))?;
+    set_field(&obj, "instruction", &JsValue::from_str(instruction))?;
+    set_field(&obj, "fields", &fields)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Account-only counterpart of `decode`: pick the state parser from the
+/// owning program and the account discriminator (`sha256("account:<Name>")`,
+/// not an instruction one) and return `{ platform, account, fields }`.
+#[wasm_bindgen(js_name = "parseAccount")]
+pub fn parse_account(program_id: &str, data: &[u8]) -> Result<JsValue, JsValue> {
+    if !ACCOUNT_ROUTES.iter().any(|r| r.program_id == program_id) {
+        return Err(JsValue::from_str("unsupported program"));
+    }
+    let route = account_route(program_id, data)
+        .ok_or_else(|| JsValue::from_str("no account parser for this program and discriminator"))?;
+
+    let obj = Object::new();
+    set_field(&obj, "platform", &JsValue::from_str(route.platform))?;
+    set_field(&obj, "account", &JsValue::from_str(route.account))?;
+    set_field(&obj, "fields", &(route.parse)(data)?)?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// Degraded `decode` output for a known program: the platform and raw bytes,
+/// split at the discriminator length that program's routes use.
+fn unknown_instruction(route: &DecodeRoute, data: &[u8]) -> Result<JsValue, JsValue> {
+    let split = route.discriminator.len().min(data.len());
+
+    let obj = Object::new();
+    set_field(&obj, "platform", &JsValue::from_str(route.platform))?;
+    set_field(&obj, "instruction", &JsValue::NULL)?;
+    set_field(&obj, "discriminatorHex", &to_hex(&data[..split]).into())?;
+    set_field(&obj, "dataHex", &to_hex(&data[split..]).into())?;
+
+    Ok(JsValue::from(obj))
+}
+
+/// `decode`, but returned as a JSON string with every 64-bit integer rendered
+/// as a quoted decimal string, ready to store without BigInt handling. The
+/// `setBigIntAsString` setting is left unchanged.
+#[wasm_bindgen(js_name = "decodeAsJson")]
+pub fn decode_as_json(program_id: &str, data: &[u8], accounts: JsValue) -> Result<String, JsValue> {
+    let previous = INT_AS_STRING.with(|c| c.replace(true));
+    let result = decode(program_id, data, accounts, None);
+    INT_AS_STRING.with(|c| c.set(previous));
+
+    JSON::stringify(&result?)?
+        .as_string()
+        .ok_or_else(|| JsValue::from_str("Serialization failed"))
+}
+
+/// Default cap on how many instructions one `parseInstructions` call accepts.
+const DEFAULT_BATCH_MAX_LEN: u32 = 256;
+
+/// Batch form of `decode`: takes an array of `{ programId, data, accounts }`
+/// and returns one `decode` result per entry, or `{ error }` where that entry
+/// failed. Arrays longer than `max_len` (default 256) are rejected outright so
+/// a single call cannot monopolize a shared worker; a `max_len` of 0 is an error.
+#[wasm_bindgen(js_name = "parseInstructions")]
+pub fn parse_instructions(instructions: JsValue, max_len: Option<u32>) -> Result<JsValue, JsValue> {
+    let items = batch_items(&instructions, max_len)?;
+
+    let decode_one = |item: &JsValue| -> Result<JsValue, JsValue> {
+        let program_id = Reflect::get(item, &"programId".into())?
+            .as_string()
+            .ok_or_else(|| JsValue::from_str("programId must be a string"))?;
+        let data = Reflect::get(item, &"data".into())?
+            .dyn_into::<Uint8Array>()
+            .map_err(|_| JsValue::from_str("data must be a Uint8Array"))?
+            .to_vec();
+        let accounts = Reflect::get(item, &"accounts".into())?;
+        decode(&program_id, &data, accounts, None)
+    };
+
+    batch_results(&items, decode_one)
+}
+
+/// Accept `instructions` as an array of at most `max_len` (default 256) items.
+fn batch_items(instructions: &JsValue, max_len: Option<u32>) -> Result<Array, JsValue> {
+    if !Array::is_array(instructions) {
+        return Err(JsValue::from_str("instructions must be an array"));
+    }
+    let items = Array::from(instructions);
+    let limit = max_len.unwrap_or(DEFAULT_BATCH_MAX_LEN);
+    if limit == 0 {
+        return Err(JsValue::from_str("maxLen must be at least 1"));
+    }
+    if items.length() > limit {
+        return Err(JsValue::from_str(&format!(
+            "too many instructions: {} exceeds maxLen {}",
+            items.length(),
+            limit
+        )));
+    }
+    Ok(items)
+}
+
+/// Run `decode_one` on every item, turning per-item failures into `{ error }`.
+fn batch_results(
+    items: &Array,
+    decode_one: impl Fn(&JsValue) -> Result<JsValue, JsValue>,
+) -> Result<JsValue, JsValue> {
+    let out = Array::new();
+    for item in items.iter() {
+        let result = decode_one(&item).or_else(|e| {
+            let err = Object::new();
+            set_field(&err, "error", &e)?;
+            Ok::<_, JsValue>(JsValue::from(err))
+        })?;
+        out.push(&result);
+    }
+
+    Ok(JsValue::from(out))
+}
+
+/// Transaction-level `decode`: `instructions` are compiled message
+/// instructions `{ programIdIndex, accounts, data }` whose `programIdIndex`
+/// and `accounts` (number array or `Uint8Array`) index into `account_keys`,
+/// as in a raw Solana message. Returns one `decode` result or `{ error }` per
+/// instruction, with the same 256-entry cap as `parseInstructions`.
+#[wasm_bindgen(js_name = "decodeTransaction")]
+pub fn decode_transaction(
+    account_keys: JsValue,
+    instructions: JsValue,
+) -> Result<JsValue, JsValue> {
+    let keys = accounts_array(&account_keys)?;
+    let items = batch_items(&instructions, None)?;
+    let index = |v: JsValue| -> Result<u32, JsValue> {
+        v.as_f64()
+            .filter(|n| n.fract() == 0.0 && *n >= 0.0 && *n <= u32::MAX as f64)
+            .map(|n| n as u32)
+            .ok_or_else(|| JsValue::from_str("account indices must be non-negative integers"))
+    };
+
+    let decode_one = |item: &JsValue| -> Result<JsValue, JsValue> {
+        let program_id = account_at(&keys, index(Reflect::get(item, &"programIdIndex".into())?)?)?;
+        let data = Reflect::get(item, &"data".into())?
+            .dyn_into::<Uint8Array>()
+            .map_err(|_| JsValue::from_str("data must be a Uint8Array"))?
+            .to_vec();
+        let indices = Reflect::get(item, &"accounts".into())?;
+        if !Array::is_array(&indices) && !indices.is_instance_of::<Uint8Array>() {
+            return Err(JsValue::from_str("accounts must be an array of indices"));
+        }
+        let accounts = Array::new();
+        for i in Array::from(&indices).iter() {
+            accounts.push(&JsValue::from_str(&account_at(&keys, index(i)?)?));
+        }
+        decode(&program_id, &data, JsValue::from(accounts), None)
+    };
+
+    batch_results(&items, decode_one)
+}
+
+#[cfg(test)]
+mod tests {
+    //! Tests that build `JsValue`s only run on wasm32
+    //! (`cargo test --target wasm32-unknown-unknown`); `unsupported = test`
+    //! marks the ones that also run natively.
+    #![cfg_attr(not(target_arch = "wasm32"), allow(dead_code, unused_imports))]
+    use super::fixtures::*;
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    mod golden;
+
+    /// Error message of a failed call.
+    fn err<T: std::fmt::Debug>(r: Result<T, JsValue>) -> String {
+        r.expect_err("expected an error")
+            .as_string()
+            .unwrap_or_default()
+    }
+
+    /// `n` distinct Base58 keys, the i-th being `[i; 32]`.
+    fn accounts(n: u8) -> JsValue {
+        let arr = Array::new();
+        for i in 0..n {
+            arr.push(&JsValue::from_str(&bs58::encode([i; 32]).into_string()));
+        }
+        arr.into()
+    }
+
+    fn key(i: u8) -> String {
+        bs58::encode([i; 32]).into_string()
+    }
+
+    fn get(obj: &JsValue, field: &str) -> JsValue {
+        Reflect::get(obj, &JsValue::from_str(field)).unwrap()
+    }
+
+    fn str_of(obj: &JsValue, field: &str) -> String {
+        get(obj, field).as_string().unwrap_or_default()
+    }
+
+    /// Run `f` with discriminator checking set to `enabled`, then restore it.
+    fn with_check<T>(enabled: bool, f: impl FnOnce() -> T) -> T {
+        let prev = CHECK_DISCRIMINATOR.with(|c| c.replace(enabled));
+        let out = f();
+        CHECK_DISCRIMINATOR.with(|c| c.set(prev));
+        out
+    }
+
+    /// `head` followed by Borsh strings.
+    fn with_strings(head: &[u8], strings: &[&str]) -> Vec<u8> {
+        let mut out = head.to_vec();
+        for s in strings {
+            out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+        out
+    }
+
+    // ---- compact-u16
+
+    fn compact(bytes: &[u8]) -> (u16, usize) {
+        let mut off = 0;
+        let v = read_compact_u16(bytes, &mut off).unwrap();
+        (v, off)
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn compact_u16_lengths() {
+        assert_eq!(compact(&[0x00]), (0, 1));
+        assert_eq!(compact(&[0x7f]), (0x7f, 1));
+        assert_eq!(compact(&[0x80, 0x01]), (0x80, 2));
+        assert_eq!(compact(&[0xff, 0x7f]), (0x3fff, 2));
+        assert_eq!(compact(&[0x80, 0x80, 0x01]), (0x4000, 3));
+        assert_eq!(compact(&[0xff, 0xff, 0x03]), (u16::MAX, 3));
+    }
+
+    #[wasm_bindgen_test]
+    fn compact_u16_rejects_bad_encodings() {
+        let mut off = 0;
+        assert!(err(read_compact_u16(&[0xff, 0xff, 0x04], &mut off)).contains("overflow"));
+        assert!(err(read_compact_u16(&[0x80, 0x00], &mut off)).contains("Non-canonical"));
+        assert!(err(read_compact_u16(&[0x80, 0x80, 0x00], &mut off)).contains("Non-canonical"));
+        assert!(err(read_compact_u16(&[0x80], &mut off)).contains("Unexpected end"));
+        assert_eq!(off, 0);
+    }
+
+    // ---- discriminator check
+
+    #[wasm_bindgen_test]
+    fn parsers_reject_a_wrong_discriminator() {
+        let mut pump = with_strings(&D_PUMP_CREATE_EVENT, &["Name", "SYM", "https://x"]);
+        pump.extend_from_slice(&[1; 96]);
+        let mut boop = D_BOOP_CREATE_TOKEN.to_vec();
+        boop.extend_from_slice(&7u64.to_le_bytes());
+        let boop = with_strings(&boop, &["Name", "SYM", "https://x"]);
+        let moonshot = with_strings(&D_MOONSHOT_TOKEN_MINT, &["Name", "SYM", "https://x"]);
+        let mut curve = D_ACC_BONDING_CURVE.to_vec();
+        curve.extend_from_slice(&[0; 41]);
+        let mut config = D_ACC_GLOBAL_CONFIG.to_vec();
+        config.extend_from_slice(&[0; 9]);
+
+        type Parse = fn(&[u8]) -> Result<JsValue, JsValue>;
+        let cases: [(Parse, Vec<u8>); 5] = [
+            (|d| parse_pump_fun_create(d, None, None), pump),
+            (parse_boop_create_token, boop),
+            (|d| parse_moonshot_token_mint(d, None), moonshot),
+            (|d| parse_pump_fun_curve_state(d, None, None), curve),
+            (parse_launchpad_global_config, config),
+        ];
+        for (parse, mut data) in cases {
+            assert!(parse(&data).is_ok());
+            data[0] ^= 0xff;
+            assert_eq!(err(parse(&data)), "discriminator mismatch");
+            assert!(with_check(false, || parse(&data)).is_ok());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn meteora_initialize_requires_an_initialize_header() {
+        let fields = ["Name", "SYM", "https://x"];
+        for head in [D_METEORA_INIT_SPL, D_METEORA_INIT_T22] {
+            let out = parse_meteora_initialize(&with_strings(&head, &fields), accounts(6)).unwrap();
+            assert_eq!(str_of(&out, "name"), "Name");
+            assert_eq!(str_of(&out, "mint"), key(3));
+        }
+
+        let wrong = with_strings(&D_BOOP_CREATE_TOKEN, &fields);
+        assert_eq!(
+            err(parse_meteora_initialize(&wrong, accounts(6))),
+            "discriminator mismatch"
+        );
+        // Header-less data is only accepted with checking off
+        let raw = with_strings(&[], &fields);
+        assert_eq!(
+            err(parse_meteora_initialize(&raw, accounts(6))),
+            "discriminator mismatch"
+        );
+        let out = with_check(false, || parse_meteora_initialize(&raw, accounts(6))).unwrap();
+        assert_eq!(str_of(&out, "symbol"), "SYM");
+    }
+
+    // ---- discriminator diagnostics
+
+    #[wasm_bindgen_test]
+    fn discriminator_hex_and_name() {
+        let mut data = D_PUMP_CREATE_EVENT.to_vec();
+        data.extend_from_slice(&[0xff; 4]);
+        assert_eq!(discriminator_hex(&data).unwrap(), "1b72a94ddeeb6376");
+        assert_eq!(
+            discriminator_name(&data, None).as_deref(),
+            Some("pump_fun:CreateEvent")
+        );
+
+        assert_eq!(discriminator_name(&[0xaa; 8], None), None);
+
+        // Shared discriminators need the program to pick a platform
+        assert_eq!(discriminator_name(&D_BUY, None).as_deref(), Some("buy"));
+        assert_eq!(
+            discriminator_name(&D_BUY, Some(PROGRAM_PUMP_FUN.into())).as_deref(),
+            Some("pump_fun:buy")
+        );
+        assert_eq!(
+            discriminator_name(&D_BUY, Some(PROGRAM_MOONSHOT.into())).as_deref(),
+            Some("moonshot:buy")
+        );
+        assert_eq!(
+            discriminator_name(&D_TRADE_EVENT, None).as_deref(),
+            Some("TradeEvent")
+        );
+        assert_eq!(
+            discriminator_name(&D_ACC_METEORA_DAMM_POOL, Some(PROGRAM_METEORA_DAMM.into()))
+                .as_deref(),
+            Some("meteora_damm:Pool")
+        );
+        assert_eq!(
+            discriminator_name(&data, Some(PROGRAM_PUMP_FUN.into())).as_deref(),
+            Some("pump_fun:CreateEvent")
+        );
+        assert_eq!(
+            discriminator_name(&data, Some(PROGRAM_MOONSHOT.into())),
+            None
+        );
+        assert_eq!(
+            discriminator_name(&D_BUY, Some("11111111111111111111111111111111".into())),
+            None
+        );
+        assert_eq!(discriminator_name(&data[..7], None), None);
+        assert_eq!(err(discriminator_hex(&data[..7])), "Data too short");
+    }
+
+    // ---- encoders
+
+    #[wasm_bindgen_test]
+    fn initialize_pool_parameters_round_trip() {
+        let params = InitializePoolParameters {
+            name: "Name".into(),
+            symbol: "SYM".into(),
+            uri: "https://x".into(),
+        };
+        let obj = to_value(&params).unwrap();
+        let data = encode_initialize_pool_parameters(obj.clone(), &D_METEORA_INIT_SPL).unwrap();
+        assert_eq!(data[..8], D_METEORA_INIT_SPL);
+
+        let decoded = InitializePoolParameters::try_from_slice(&data[8..]).unwrap();
+        assert_eq!(
+            (decoded.name, decoded.symbol, decoded.uri),
+            (params.name, params.symbol, params.uri)
+        );
+        let out = parse_meteora_initialize(&data, accounts(6)).unwrap();
+        assert_eq!(str_of(&out, "uri"), "https://x");
+
+        assert_eq!(
+            err(encode_initialize_pool_parameters(obj, &[0; 7])),
+            "Discriminator must be 8 bytes"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn curve_params_round_trip() {
+        let curve = CurveParams::Linear {
+            data: LinearCurve {
+                supply: 1 << 40,
+                total_quote_fund_raising: 85_000_000_000,
+                migrate_type: 1,
+            },
+        };
+        let data = encode_curve_params(to_value(&curve).unwrap(), &D_RAY_INIT).unwrap();
+        // Borsh enum tag: Constant = 0, Fixed = 1, Linear = 2
+        assert_eq!(data[8], 2);
+        match CurveParams::try_from_slice(&data[8..]).unwrap() {
+            CurveParams::Linear { data } => {
+                assert_eq!(data.supply, 1 << 40);
+                assert_eq!(data.total_quote_fund_raising, 85_000_000_000);
+                assert_eq!(data.migrate_type, 1);
+            }
+            _ => panic!("expected Linear"),
+        }
+        assert!(err(encode_curve_params(
+            JsValue::from_str("Linear"),
+            &D_RAY_INIT
+        ))
+        .starts_with("Invalid object"));
+    }
+
+    #[wasm_bindgen_test]
+    fn meteora_initialize_with_config_accounts() {
+        let fields = ["Name", "SYM", "https://x"];
+        let data = with_strings(&D_METEORA_INIT_T22, &fields);
+        let out = parse_meteora_initialize_with_config(&data, accounts(8)).unwrap();
+        assert_eq!(str_of(&out, "config"), key(0));
+        assert_eq!(str_of(&out, "quote_mint"), key(4));
+        assert_eq!(str_of(&out, "quote_vault"), key(7));
+        assert_eq!(str_of(&out, "token_type"), "token2022");
+
+        let wrong = with_strings(&D_BUY, &fields);
+        assert_eq!(
+            err(parse_meteora_initialize_with_config(&wrong, accounts(8))),
+            "discriminator mismatch"
+        );
+        let raw = with_strings(&[], &fields);
+        let out = with_check(false, || {
+            parse_meteora_initialize_with_config(&raw, accounts(8))
+        })
+        .unwrap();
+        assert!(get(&out, "token_type").as_string().is_none());
+    }
+
+    // ---- accounts
+
+    #[wasm_bindgen_test]
+    fn account_at_reports_the_index() {
+        let accs = Array::from(&accounts(3));
+        assert_eq!(account_at(&accs, 2).unwrap(), key(2));
+        assert_eq!(err(account_at(&accs, 3)), "missing account at index 3");
+
+        accs.push(&JsValue::from_f64(1.0));
+        assert_eq!(
+            err(account_at(&accs, 3)),
+            "account at index 3 is not a string"
+        );
+    }
+
+    // ---- pricing
+
+    fn big(obj: &JsValue, field: &str) -> u64 {
+        u64::try_from(BigInt::from(get(obj, field))).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn pump_fun_fees_round_up_on_top_of_buys_and_off_sells() {
+        // 1 SOL through the curve at 95 + 5 bps: a buy pays 1.01 SOL, a sell
+        // receives 0.99 SOL
+        let out = compute_pump_fun_fees(1_000_000_000, 95, 5, "buy").unwrap();
+        assert_eq!(big(&out, "protocolFee"), 9_500_000);
+        assert_eq!(big(&out, "creatorFee"), 500_000);
+        assert_eq!(big(&out, "userAmount"), 1_010_000_000);
+        let out = compute_pump_fun_fees(1_000_000_000, 95, 5, "sell").unwrap();
+        assert_eq!(big(&out, "userAmount"), 990_000_000);
+
+        // 1_999 * 95 / 10_000 = 18.99 and 1_999 * 5 / 10_000 = 0.99 each round up
+        let out = compute_pump_fun_fees(1_999, 95, 5, "buy").unwrap();
+        assert_eq!(big(&out, "protocolFee"), 19);
+        assert_eq!(big(&out, "creatorFee"), 1);
+        assert_eq!(big(&out, "userAmount"), 2_019);
+        let out = compute_pump_fun_fees(1_999, 95, 5, "sell").unwrap();
+        assert_eq!(big(&out, "userAmount"), 1_979);
+
+        // No creator fee rounds to zero, not one
+        let out = compute_pump_fun_fees(12_345_679, 100, 0, "sell").unwrap();
+        assert_eq!(big(&out, "protocolFee"), 123_457);
+        assert_eq!(big(&out, "creatorFee"), 0);
+
+        let out = compute_pump_fun_fees(u64::MAX / 2, 100, 0, "sell").unwrap();
+        assert_eq!(big(&out, "protocolFee"), (u64::MAX / 2).div_ceil(100));
+
+        assert_eq!(
+            err(compute_pump_fun_fees(100, 6_000, 5_000, "sell")),
+            "Fees exceed amount"
+        );
+        assert_eq!(
+            err(compute_pump_fun_fees(u64::MAX, 100, 0, "buy")),
+            "Amount overflows u64"
+        );
+        assert_eq!(
+            err(compute_pump_fun_fees(100, 10_001, 0, "buy")),
+            "Fee bps must be at most 10000"
+        );
+        assert_eq!(
+            err(compute_pump_fun_fees(100, 95, 5, "swap")),
+            "side must be \"buy\" or \"sell\""
+        );
+    }
+
+    // ---- name / symbol
+
+    #[wasm_bindgen_test]
+    fn token_name_symbol_across_platforms() {
+        let fields = ["Name", "SYM", "https://x"];
+        for head in [D_PUMP_CREATE, D_PUMP_CREATE_EVENT] {
+            let out =
+                parse_token_name_symbol(PROGRAM_PUMP_FUN, &with_strings(&head, &fields)).unwrap();
+            assert_eq!(str_of(&out, "name"), "Name");
+        }
+        let out = parse_token_name_symbol(
+            PROGRAM_MOONSHOT,
+            &with_strings(&D_MOONSHOT_TOKEN_MINT, &fields),
+        )
+        .unwrap();
+        assert_eq!(str_of(&out, "symbol"), "SYM");
+
+        // decimals, strings, then Linear curve (tag 2) and zeroed vesting
+        let mut head = D_RAY_INIT.to_vec();
+        head.push(6);
+        let mut data = with_strings(&head, &fields);
+        data.push(2);
+        data.extend_from_slice(&[0; 17 + 24]);
+        let out = parse_token_name_symbol(PROGRAM_RAYDIUM_LAUNCHPAD, &data).unwrap();
+        assert_eq!(str_of(&out, "uri"), "https://x");
+
+        assert_eq!(
+            err(parse_token_name_symbol(
+                "11111111111111111111111111111111",
+                &data
+            )),
+            "unsupported program"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn token_name_symbol_follows_discriminator_check() {
+        let mut data = with_strings(&D_PUMP_CREATE, &["Name", "SYM", "https://x"]);
+        data[..8].copy_from_slice(&[0xaa; 8]);
+        let parse = || parse_token_name_symbol(PROGRAM_PUMP_FUN, &data);
+        assert_eq!(err(with_check(true, parse)), "not a Pump.fun create");
+        assert_eq!(str_of(&with_check(false, parse).unwrap(), "name"), "Name");
+    }
+
+    #[wasm_bindgen_test]
+    fn meteora_initialize_rejects_bad_accounts() {
+        let data = with_strings(&D_METEORA_INIT_SPL, &["Name", "SYM", "https://x"]);
+        for accs in [
+            JsValue::UNDEFINED,
+            JsValue::NULL,
+            JsValue::from_str(&key(0)),
+        ] {
+            assert_eq!(
+                err(parse_meteora_initialize(&data, accs)),
+                "accounts must be an array of base58 strings"
+            );
+        }
+        for n in [0, 5] {
+            assert_eq!(
+                err(parse_meteora_initialize(&data, accounts(n))),
+                format!(
+                    "Meteora initialize needs accounts at indices 2, 3, 5 (got {})",
+                    n
+                )
+            );
+        }
+        assert!(parse_meteora_initialize(&data, accounts(6)).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn price_impact_worked_examples() {
+        // 1 SOL into Pump.fun's initial 30 SOL / 1.073B token virtual reserves
+        let out =
+            compute_price_impact(30_000_000_000, 1_073_000_000_000_000, 1_000_000_000).unwrap();
+        assert_eq!(big(&out, "tokensOut"), 34_612_903_225_806);
+        assert_eq!(get(&out, "impactBps").as_f64(), Some(322.0));
+        let price = get(&out, "newPrice").as_f64().unwrap();
+        assert!((price - 31e9 / 1_038_387_096_774_194.0).abs() < 1e-15);
+
+        let out = compute_price_impact(30_000_000_000, 1_073_000_000_000_000, 0).unwrap();
+        assert_eq!(big(&out, "tokensOut"), 0);
+        assert_eq!(get(&out, "impactBps").as_f64(), Some(0.0));
+
+        // u128 intermediates keep u64::MAX inputs exact
+        let out = compute_price_impact(u64::MAX, u64::MAX, u64::MAX).unwrap();
+        assert_eq!(big(&out, "tokensOut"), u64::MAX / 2);
+        assert_eq!(get(&out, "impactBps").as_f64(), Some(5_000.0));
+
+        for (vs, vt) in [(0, 1), (1, 0)] {
+            assert_eq!(
+                err(compute_price_impact(vs, vt, 1)),
+                "Reserves must be non-zero"
+            );
+        }
+    }
+
+    // ---- integer representation
+
+    #[wasm_bindgen_test]
+    fn curve_state_ints_as_bigint_or_string() {
+        let mut data = D_ACC_BONDING_CURVE.to_vec();
+        for v in [
+            1_073_000_000_000_000u64,
+            30_000_000_000,
+            793_100_000_000_000,
+            0,
+            u64::MAX,
+        ] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.push(1);
+
+        let out = parse_pump_fun_curve_state(&data, None, None).unwrap();
+        assert!(get(&out, "virtual_sol_reserves").is_bigint());
+        assert_eq!(big(&out, "token_total_supply"), u64::MAX);
+
+        set_bigint_as_string(true);
+        let out = parse_pump_fun_curve_state(&data, None, None);
+        set_bigint_as_string(false);
+        let out = out.unwrap();
+        assert_eq!(str_of(&out, "virtual_sol_reserves"), "30000000000");
+        assert_eq!(str_of(&out, "token_total_supply"), u64::MAX.to_string());
+        assert_eq!(get(&out, "complete").as_bool(), Some(true));
+    }
+
+    #[wasm_bindgen_test]
+    fn init_is_idempotent() {
+        init();
+        init();
+        assert_eq!(discriminator_hex(&D_BUY).unwrap(), "66063d1201daebea");
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn version_is_semver() {
+        let v = version();
+        let parts: Vec<&str> = v.split('.').collect();
+        assert_eq!(parts.len(), 3, "{}", v);
+        assert!(parts
+            .iter()
+            .all(|p| !p.is_empty() && p.parse::<u32>().is_ok()));
+    }
+
+    #[wasm_bindgen_test]
+    fn list_parsers_includes_known_entries() {
+        let list = Array::from(&list_parsers().unwrap());
+        assert_eq!(list.length() as usize, PARSERS.len());
+        let find = |name: &str| {
+            list.iter()
+                .find(|e| str_of(e, "name") == name)
+                .unwrap_or_else(|| panic!("{} not listed", name))
+        };
+
+        let pump = find("parsePumpFunCreate");
+        assert_eq!(str_of(&pump, "platform"), "pump_fun");
+        assert_eq!(str_of(&pump, "kind"), "event");
+        let discs = Array::from(&get(&pump, "discriminators"));
+        assert_eq!(
+            discs.get(0).as_string().unwrap(),
+            to_hex(&D_PUMP_CREATE_EVENT)
+        );
+
+        let raydium = find("parseRaydiumInitialize");
+        assert_eq!(Array::from(&get(&raydium, "discriminators")).length(), 3);
+        assert_eq!(
+            str_of(&find("parseMoonshotTokenMint"), "platform"),
+            "moonshot"
+        );
+        assert_eq!(
+            Array::from(&get(&find("parseTokenNameSymbol"), "discriminators")).length(),
+            0
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn lossy_strings_replace_invalid_utf8() {
+        // "Na\xffme": 0xff is never valid UTF-8
+        let mut data = D_PUMP_CREATE_EVENT.to_vec();
+        data.extend_from_slice(&5u32.to_le_bytes());
+        data.extend_from_slice(b"Na\xffme");
+        let mut data = with_strings(&data, &["SYM", "https://x"]);
+        data.extend_from_slice(&[1; 96]);
+
+        assert_eq!(
+            err(parse_pump_fun_create(&data, None, None)),
+            "Invalid UTF-8 at offset 0"
+        );
+        assert_eq!(
+            err(parse_pump_fun_create(&data, Some(false), None)),
+            "Invalid UTF-8 at offset 0"
+        );
+        let out = parse_pump_fun_create(&data, Some(true), None).unwrap();
+        assert_eq!(str_of(&out, "name"), "Na\u{fffd}me");
+        assert_eq!(str_of(&out, "symbol"), "SYM");
+
+        let mut off = 0;
+        let raw = [3, 0, 0, 0, b'a', 0xc3, b'b'];
+        assert_eq!(read_string_lossy(&raw, &mut off).unwrap(), "a\u{fffd}b");
+        assert_eq!(off, raw.len());
+    }
+
+    #[wasm_bindgen_test]
+    fn detect_migration_per_destination() {
+        let cases = [
+            (PROGRAM_PUMP_FUN, D_PUMP_MIGRATE, "pumpswap", 9, 2),
+            (
+                PROGRAM_RAYDIUM_LAUNCHPAD,
+                D_RAY_MIGRATE_TO_AMM,
+                "raydium_amm_v4",
+                13,
+                1,
+            ),
+            (
+                PROGRAM_RAYDIUM_LAUNCHPAD,
+                D_RAY_MIGRATE_TO_CPSWAP,
+                "raydium_cpmm",
+                5,
+                1,
+            ),
+            (
+                PROGRAM_METEORA_DBC,
+                D_METEORA_MIGRATE_DAMM,
+                "meteora_damm",
+                4,
+                7,
+            ),
+            (
+                PROGRAM_METEORA_DBC,
+                D_METEORA_MIGRATE_DAMM_V2,
+                "meteora_damm_v2",
+                4,
+                13,
+            ),
+            (
+                PROGRAM_BOOP,
+                D_BOOP_CREATE_RAYDIUM_POOL,
+                "raydium_cpmm",
+                3,
+                4,
+            ),
+        ];
+        for (program, disc, destination, pool, mint) in cases {
+            let out = detect_migration(program, &disc, accounts(20)).unwrap();
+            assert_eq!(str_of(&out, "destination"), destination);
+            assert_eq!(str_of(&out, "pool"), key(pool));
+            assert_eq!(str_of(&out, "mint"), key(mint));
+        }
+
+        // Right discriminator under the wrong program
+        assert_eq!(
+            err(detect_migration(
+                PROGRAM_BOOP,
+                &D_PUMP_MIGRATE,
+                accounts(20)
+            )),
+            "not a known migration ix"
+        );
+        assert_eq!(
+            err(detect_migration(
+                PROGRAM_PUMP_FUN,
+                &D_PUMP_MIGRATE,
+                accounts(9)
+            )),
+            "missing account at index 9"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn fixed_strings_trim_nul_padding() {
+        let mut off = 0;
+        assert_eq!(read_fixed_string(&[0; 8], &mut off, 8).unwrap(), "");
+        assert_eq!(off, 8);
+
+        let mut buf = *b"Pepe\0\0\0\0tail";
+        off = 0;
+        assert_eq!(read_fixed_string(&buf, &mut off, 8).unwrap(), "Pepe");
+        assert_eq!(off, 8);
+        // Only trailing NULs are trimmed; a full field has none
+        off = 0;
+        buf[1] = 0;
+        assert_eq!(read_fixed_string(&buf, &mut off, 4).unwrap(), "P\0pe");
+
+        buf[0] = 0xff;
+        off = 0;
+        assert_eq!(
+            err(read_fixed_string(&buf, &mut off, 8)),
+            "Invalid UTF-8 at offset 0"
+        );
+        assert_eq!(off, 0);
+        assert_eq!(
+            err(read_fixed_string(&buf, &mut off, 13)),
+            "Fixed string exceeds buffer at offset 0"
+        );
+    }
+
+    // ---- fixture builders
+
+    #[wasm_bindgen_test]
+    fn fixtures_round_trip_through_parsers() {
+        type WithAccounts = fn(&[u8], JsValue) -> Result<JsValue, JsValue>;
+        type WithStrict = fn(&[u8], JsValue, Option<bool>) -> Result<JsValue, JsValue>;
+        let out = parse_pump_fun_create(
+            &build_pump_fun_create("Name", "SYM", "https://x", &[1; 32], &[2; 32], &[3; 32]),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(str_of(&out, "uri"), "https://x");
+        assert_eq!(str_of(&out, "mint"), key(1));
+        assert_eq!(str_of(&out, "pool"), key(2));
+        assert_eq!(str_of(&out, "developer"), key(3));
+
+        let out = parse_pump_fun_complete_event(
+            &build_pump_fun_complete_event(&[4; 32], &[5; 32], &[6; 32], -7),
+            None,
+        )
+        .unwrap();
+        assert_eq!(str_of(&out, "user"), key(4));
+        assert_eq!(str_of(&out, "bondingCurve"), key(6));
+        assert_eq!(BigInt::from(get(&out, "timestamp")), BigInt::from(-7i64));
+
+        let out = parse_pump_fun_curve_state(
+            &build_pump_fun_curve_state(1, 2, 3, 4, 5, true),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(big(&out, "real_sol_reserves"), 4);
+        assert_eq!(get(&out, "complete").as_bool(), Some(true));
+
+        let out = parse_boop_create_token(&build_boop_create_token(9, "Name", "SYM", "https://x"))
+            .unwrap();
+        assert_eq!(str_of(&out, "symbol"), "SYM");
+        for (sell, parse) in [
+            (false, parse_boop_buy as WithAccounts),
+            (true, parse_boop_sell),
+        ] {
+            let out = parse(&build_boop_trade(sell, 100, 90), accounts(7)).unwrap();
+            assert_eq!(big(&out, "amountIn"), 100);
+            assert_eq!(big(&out, "minimumAmountOut"), 90);
+        }
+
+        let out =
+            parse_moonshot_token_mint(&build_moonshot_token_mint("Name", "SYM", "https://x"), None)
+                .unwrap();
+        assert_eq!(str_of(&out, "name"), "Name");
+        for (sell, parse) in [
+            (
+                false,
+                parse_moonshot_buy as fn(&[u8]) -> Result<JsValue, JsValue>,
+            ),
+            (true, parse_moonshot_sell),
+        ] {
+            let out = parse(&build_moonshot_trade(sell, 10, 20, 1, 50)).unwrap();
+            assert_eq!(big(&out, "collateralAmount"), 20);
+            assert_eq!(str_of(&out, "fixedSide"), "ExactOut");
+            assert_eq!(big(&out, "slippageBps"), 50);
+        }
+
+        let trades = [
+            (false, parse_raydium_launchpad_buy_exact_in as WithStrict),
+            (true, parse_raydium_launchpad_sell_exact_in),
+        ];
+        for (sell, parse) in trades {
+            let data = build_launchpad_trade(sell, 1_000, 900, 25);
+            let out = parse(&data, accounts(11), None).unwrap();
+            assert_eq!(big(&out, "shareFeeRate"), 25);
+            assert_eq!(str_of(&out, "baseMint"), key(9));
+        }
+
+        for token2022 in [false, true] {
+            let data = build_meteora_initialize(token2022, "Name", "SYM", "https://x");
+            let out = parse_meteora_initialize(&data, accounts(6)).unwrap();
+            assert_eq!(str_of(&out, "pool"), key(5));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn overflowing_lengths_are_rejected() {
+        // A u32 length of 0xffff_ffff at a non-zero offset wraps a 32-bit
+        // `off + len`; it must fail cleanly instead of slicing.
+        let mut buf = vec![0; 4];
+        buf.extend_from_slice(&u32::MAX.to_le_bytes());
+        buf.extend_from_slice(b"abc");
+        let mut off = 4;
+        assert_eq!(
+            err(read_string(&buf, &mut off)),
+            "String length exceeds buffer at offset 8"
+        );
+
+        assert_eq!(checked_end(&buf, usize::MAX, 1), None);
+        assert_eq!(checked_end(&buf, 1, usize::MAX), None);
+        assert_eq!(checked_end(&buf, 4, 7), Some(11));
+        assert_eq!(checked_end(&buf, 4, 8), None);
+
+        let mut off = usize::MAX - 2;
+        assert_eq!(
+            err(read_u64(&buf, &mut off)),
+            format!("Unexpected buffer length at offset {}", off)
+        );
+        assert_eq!(
+            err(read_fixed_string(&buf, &mut off, 8)),
+            format!("Fixed string exceeds buffer at offset {}", off)
+        );
+        assert_eq!(
+            err(read_compact_u16(&buf, &mut off)),
+            format!("Unexpected end of compact-u16 at offset {}", off)
+        );
+        assert_eq!(off, usize::MAX - 2);
+    }
+
+    #[wasm_bindgen_test]
+    fn skip_past_the_end_fails() {
+        let buf = [0; 8];
+        let mut off = 2;
+        assert!(skip(&buf, &mut off, 6).is_ok());
+        assert_eq!(off, 8);
+        assert_eq!(
+            err(skip(&buf, &mut off, 1)),
+            "Skip past end of buffer at offset 8"
+        );
+        assert_eq!(
+            err(skip(&buf, &mut off, usize::MAX)),
+            "Skip past end of buffer at offset 8"
+        );
+        assert_eq!(off, 8);
+
+        // Pool state cut off inside the skipped platform_config / base_mint
+        let mut pool = D_ACC_POOL_STATE.to_vec();
+        pool.extend_from_slice(&[0; 8 + 5 + 15 * 8 + 32 + 63]);
+        assert_eq!(
+            err(parse_launchpad_pool_state(&pool, None, None)),
+            "Skip past end of buffer at offset 165"
+        );
+        let mut vesting = D_ACC_VESTING_RECORD.to_vec();
+        vesting.extend_from_slice(&[0; 200]);
+        assert_eq!(
+            err(parse_launchpad_vesting(&vesting, &pool[..8 + 90])),
+            "Skip past end of buffer at offset 0"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn remainder_hex_holds_unconsumed_bytes() {
+        let mut data = build_pump_fun_curve_state(1, 2, 3, 4, 5, false);
+        // Trailing bytes past the decoded fields
+        data.extend_from_slice(&[0xab, 0xcd, 0x00]);
+        let out = parse_pump_fun_curve_state(&data, Some(true), None).unwrap();
+        assert_eq!(str_of(&out, "remainderHex"), "abcd00");
+        let out = parse_pump_fun_curve_state(&data, None, None).unwrap();
+        assert!(get(&out, "remainderHex").is_undefined());
+
+        let out = parse_pump_fun_curve_state(
+            &build_pump_fun_curve_state(1, 2, 3, 4, 5, false),
+            Some(true),
+            None,
+        )
+        .unwrap();
+        assert_eq!(str_of(&out, "remainderHex"), "");
+
+        // PoolState: 8 + 5 + 15 * 8 + 4 * 32 bytes are decoded or skipped
+        let mut pool = D_ACC_POOL_STATE.to_vec();
+        pool.extend_from_slice(&[0; 8 + 5 + 15 * 8 + 4 * 32]);
+        pool.extend_from_slice(&[0x01, 0xff]);
+        let out = parse_launchpad_pool_state(&pool, Some(true), None).unwrap();
+        assert_eq!(str_of(&out, "remainderHex"), "01ff");
+    }
+
+    #[wasm_bindgen_test]
+    fn daos_fun_create_resolves_accounts() {
+        let data = build_meteora_initialize(false, "DAO", "DAO", "https://x");
+        let out = parse_daos_fun_create(&data, accounts(6)).unwrap();
+        assert_eq!(str_of(&out, "name"), "DAO");
+        assert_eq!(str_of(&out, "dao_config"), key(0));
+        assert_eq!(str_of(&out, "developer"), key(2));
+        assert_eq!(str_of(&out, "mint"), key(3));
+        assert_eq!(str_of(&out, "pool"), key(5));
+        assert_eq!(
+            err(parse_daos_fun_create(&data, accounts(5))),
+            "Daos.fun create needs accounts at indices 0, 2, 3, 5 (got 5)"
+        );
+
+        let mut wrong = data.clone();
+        wrong[0] ^= 0xff;
+        assert_eq!(
+            err(parse_daos_fun_create(&wrong, accounts(6))),
+            "discriminator mismatch"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn create_parsers_share_the_token_create_shape() {
+        let mut ray = D_RAY_INIT.to_vec();
+        ray.push(6);
+        let mut ray = with_strings(&ray, &["Name", "SYM", "https://x"]);
+        ray.push(2);
+        ray.extend_from_slice(&[0; 17 + 24]);
+        let meteora = build_meteora_initialize(false, "Name", "SYM", "https://x");
+
+        let outs = [
+            (
+                "pump_fun",
+                parse_pump_fun_create(
+                    &build_pump_fun_create(
+                        "Name",
+                        "SYM",
+                        "https://x",
+                        &[1; 32],
+                        &[2; 32],
+                        &[3; 32],
+                    ),
+                    None,
+                    None,
+                ),
+            ),
+            (
+                "boop",
+                parse_boop_create_token(&build_boop_create_token(1, "Name", "SYM", "https://x")),
+            ),
+            (
+                "moonshot",
+                parse_moonshot_token_mint(
+                    &build_moonshot_token_mint("Name", "SYM", "https://x"),
+                    None,
+                ),
+            ),
+            ("raydium_launchpad", parse_raydium_initialize(&ray)),
+            (
+                "meteora_dbc",
+                parse_meteora_initialize(&meteora, accounts(6)),
+            ),
+            ("daos_fun", parse_daos_fun_create(&meteora, accounts(6))),
+        ];
+        for (platform, out) in outs {
+            let out = out.unwrap();
+            assert_eq!(str_of(&out, "platform"), platform);
+            assert_eq!(str_of(&out, "name"), "Name");
+            assert_eq!(str_of(&out, "symbol"), "SYM");
+            assert_eq!(str_of(&out, "uri"), "https://x");
+            // Unknown fields are present as null, never missing
+            for field in ["mint", "pool", "developer"] {
+                let v = get(&out, field);
+                assert!(v.is_null() || v.is_string(), "{} {}", platform, field);
+            }
+        }
+
+        let out = parse_boop_create_token(&build_boop_create_token(1, "Name", "SYM", "https://x"))
+            .unwrap();
+        assert!(get(&out, "mint").is_null());
+        let out = parse_meteora_initialize(&meteora, accounts(6)).unwrap();
+        assert_eq!(str_of(&out, "developer"), key(2));
+    }
+
+    #[wasm_bindgen_test]
+    fn trade_parsers_normalize_limits() {
+        let check = |out: JsValue, side: &str, amount: u64, limit: u64, kind: &str| {
+            assert_eq!(str_of(&out, "side"), side);
+            assert_eq!(big(&out, "amount"), amount);
+            assert_eq!(big(&out, "limitAmount"), limit);
+            assert_eq!(str_of(&out, "limitKind"), kind);
+        };
+
+        check(
+            parse_boop_buy(&build_boop_trade(false, 100, 90), accounts(7)).unwrap(),
+            "buy",
+            100,
+            90,
+            "min_out",
+        );
+        check(
+            parse_boop_sell(&build_boop_trade(true, 100, 90), accounts(7)).unwrap(),
+            "sell",
+            100,
+            90,
+            "min_out",
+        );
+        let out = parse_raydium_launchpad_sell_exact_in(
+            &build_launchpad_trade(true, 1_000, 900, 0),
+            accounts(11),
+            None,
+        );
+        check(out.unwrap(), "sell", 1_000, 900, "min_out");
+
+        // Moonshot (tokens 10, collateral 20): ExactIn fixes the input side,
+        // ExactOut the output side
+        let moonshot = |sell, fixed_side| build_moonshot_trade(sell, 10, 20, fixed_side, 50);
+        check(
+            parse_moonshot_buy(&moonshot(false, 0)).unwrap(),
+            "buy",
+            20,
+            10,
+            "min_out",
+        );
+        check(
+            parse_moonshot_buy(&moonshot(false, 1)).unwrap(),
+            "buy",
+            10,
+            20,
+            "max_in",
+        );
+        check(
+            parse_moonshot_sell(&moonshot(true, 0)).unwrap(),
+            "sell",
+            10,
+            20,
+            "min_out",
+        );
+        check(
+            parse_moonshot_sell(&moonshot(true, 1)).unwrap(),
+            "sell",
+            20,
+            10,
+            "max_in",
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn decode_errors_name_the_offset() {
+        // Moonshot symbol length runs past the end of the 12-byte buffer
+        let mut data = with_strings(&D_MOONSHOT_TOKEN_MINT, &["Name"]);
+        data.extend_from_slice(&100u32.to_le_bytes());
+        assert_eq!(
+            err(parse_moonshot_token_mint(&data, None)),
+            "Failed to parse symbol: String length exceeds buffer at offset 12"
+        );
+
+        // Global cut one byte into its last u64 (`fee_basis_points`)
+        let data = build_pump_fun_global(1, 2, 3, 4, 5);
+        assert_eq!(
+            err(parse_pump_fun_global(&data[..data.len() - 1])),
+            "Unexpected buffer length at offset 97"
+        );
+
+        // Borsh: the Boop trade's second u64 is cut short; the offset is how
+        // far Borsh got before running out of input
+        let data = build_boop_trade(false, 1, 2);
+        assert_eq!(
+            err(parse_boop_buy(&data[..data.len() - 1], accounts(7))),
+            "Deserialization failed at offset 15: Unexpected length of input"
+        );
+        let mut long = data.clone();
+        long.push(0);
+        assert_eq!(
+            err(parse_boop_buy(&long, accounts(7))),
+            "Deserialization failed: 1 trailing bytes at offset 16"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn launchpad_quotes_per_curve() {
+        let quote = |curve, base, quote, amount, direction| {
+            u64::try_from(BigInt::from(
+                compute_launchpad_quote(curve, base, quote, amount, direction).unwrap(),
+            ))
+            .unwrap()
+        };
+        // Constant product over 1.073B base / 30 quote virtual reserves
+        assert_eq!(
+            quote(
+                0,
+                1_073_000_000_000_000,
+                30_000_000_000,
+                1_000_000_000,
+                "buy"
+            ),
+            34_612_903_225_806
+        );
+        assert_eq!(
+            quote(
+                0,
+                1_073_000_000_000_000,
+                30_000_000_000,
+                1_000_000_000,
+                "sell"
+            ),
+            27_958
+        );
+
+        // Fixed price of 3 quote per 1000 base, rounded down
+        assert_eq!(quote(1, 1_000, 3, 10, "buy"), 3_333);
+        assert_eq!(quote(1, 1_000, 3, 10, "sell"), 0);
+
+        // Linear with slope a = 0.5 (Q64.64): buying 25 quote from zero supply
+        // mints sqrt(2 * 25 / 0.5) = 10 base, and selling them back returns 25
+        let a = 1u64 << 63;
+        assert_eq!(quote(2, 0, a, 25, "buy"), 10);
+        assert_eq!(quote(2, 10, a, 10, "sell"), 25);
+        // Too little quote for a whole token at supply 1000 rounds down to 0
+        assert_eq!(quote(2, 1_000, a, 7, "buy"), 0);
+
+        assert_eq!(
+            err(compute_launchpad_quote(3, 1, 1, 1, "buy")),
+            "Unknown curve type"
+        );
+        assert_eq!(
+            err(compute_launchpad_quote(0, 0, 0, 0, "buy")),
+            "Reserves must be non-zero"
+        );
+        assert_eq!(
+            err(compute_launchpad_quote(2, 5, a, 6, "sell")),
+            "Sell exceeds supply"
+        );
+        assert_eq!(
+            err(compute_launchpad_quote(0, 1, 1, 1, "swap")),
+            "direction must be \"buy\" or \"sell\""
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn program_data_log_lines() {
+        assert_eq!(
+            extract_program_data("Program data: ZgY9EgHa6+o=").unwrap(),
+            D_BUY
+        );
+        assert_eq!(
+            extract_program_data("  Program data: aGVsbG8=\n").unwrap(),
+            b"hello"
+        );
+        assert_eq!(
+            extract_program_data(&format!("Program return: {} aGVsbG8", PROGRAM_PUMP_FUN)).unwrap(),
+            b"hello"
+        );
+
+        assert_eq!(
+            err(extract_program_data("Program log: Instruction: Buy")),
+            "not a Program data log line"
+        );
+        assert_eq!(
+            err(extract_program_data("Program return: aGVsbG8=")),
+            "Program return line has no data"
+        );
+        assert_eq!(
+            err(extract_program_data("Program data: aGV$bG8=")),
+            "Invalid base64 character"
+        );
+        assert_eq!(
+            err(extract_program_data("Program data: aGVsb")),
+            "Invalid base64 length"
+        );
+        assert_eq!(
+            err(extract_program_data("Program data: aGVs===")),
+            "Invalid base64 length"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn pool_state_accumulators_are_read_as_u64_array() {
+        let mut buf = vec![0xee];
+        for v in 1..=8u64 {
+            buf.extend_from_slice(&(v << 40).to_le_bytes());
+        }
+        let mut off = 1;
+        let arr = read_u64_array::<8>(&buf, &mut off).unwrap();
+        assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8].map(|v: u64| v << 40));
+        assert_eq!(off, buf.len());
+        off = 1;
+        assert!(read_u64_array::<9>(&buf, &mut off).is_err());
+
+        // PoolState: epoch, 5 u8s, 7 u64s, then the 8 accumulators
+        let mut pool = D_ACC_POOL_STATE.to_vec();
+        pool.extend_from_slice(&[0; 8 + 5 + 7 * 8]);
+        pool.extend_from_slice(&buf[1..]);
+        pool.extend_from_slice(&[0; 4 * 32]);
+        let out = parse_launchpad_pool_state(&pool, None, None).unwrap();
+        let acc = Array::from(&get(&out, "accumulators"));
+        assert_eq!(acc.length(), 8);
+        assert_eq!(u64::try_from(BigInt::from(acc.get(7))).unwrap(), 8 << 40);
+    }
+
+    #[wasm_bindgen_test]
+    fn strict_mode_rejects_trailing_bytes() {
+        let create =
+            build_pump_fun_create("Name", "SYM", "https://x", &[1; 32], &[2; 32], &[3; 32]);
+        let complete = build_pump_fun_complete_event(&[4; 32], &[5; 32], &[6; 32], 7);
+        let trade = build_launchpad_trade(false, 1, 2, 3);
+        let mut set_params = D_PUMP_SET_PARAMS.to_vec();
+        set_params.extend_from_slice(&[0; 32 + 5 * 8]);
+
+        // Exact fit passes with and without strict
+        for strict in [None, Some(false), Some(true)] {
+            assert!(parse_pump_fun_create(&create, None, strict).is_ok());
+            assert!(parse_pump_fun_complete_event(&complete, strict).is_ok());
+            assert!(parse_pump_fun_set_params(&set_params, strict).is_ok());
+            assert!(parse_raydium_launchpad_buy_exact_in(&trade, accounts(11), strict).is_ok());
+        }
+
+        let trailing = |data: &[u8]| [data, &[0, 0]].concat();
+        let (create, complete) = (trailing(&create), trailing(&complete));
+        let (trade, set_params) = (trailing(&trade), trailing(&set_params));
+        assert!(parse_pump_fun_create(&create, None, None).is_ok());
+        assert_eq!(
+            err(parse_pump_fun_create(&create, None, Some(true))),
+            format!("2 trailing bytes at offset {}", create.len() - 10)
+        );
+        assert_eq!(
+            err(parse_pump_fun_complete_event(&complete, Some(true))),
+            "2 trailing bytes at offset 104"
+        );
+        assert_eq!(
+            err(parse_raydium_launchpad_buy_exact_in(
+                &trade,
+                accounts(11),
+                Some(true)
+            )),
+            "2 trailing bytes at offset 24"
+        );
+        assert!(parse_pump_fun_set_params(&set_params, None).is_ok());
+        assert_eq!(
+            err(parse_pump_fun_set_params(&set_params, Some(true))),
+            "Deserialization failed: 2 trailing bytes at offset 72"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn decode_pubkey_requires_32_bytes() {
+        assert_eq!(decode_pubkey(&key(7)).unwrap(), [7; 32]);
+        assert_eq!(decode_pubkey(PROGRAM_PUMP_FUN).unwrap().len(), 32);
+        assert_eq!(
+            decode_pubkey("11111111111111111111111111111111").unwrap(),
+            [0; 32]
+        );
+
+        let short = bs58::encode([7; 31]).into_string();
+        assert_eq!(
+            err(decode_pubkey(&short)),
+            "pubkey must be 32 bytes (got 31)"
+        );
+        assert_eq!(err(decode_pubkey("")), "pubkey must be 32 bytes (got 0)");
+        // 0, O, I and l are not in the Base58 alphabet
+        assert!(err(decode_pubkey("0OIl")).starts_with("Invalid Base58"));
+    }
+
+    #[wasm_bindgen_test]
+    fn decode_routes_with_and_without_accounts() {
+        let data = build_meteora_initialize(true, "Name", "SYM", "https://x");
+        let out = decode(PROGRAM_METEORA_DBC, &data, accounts(6), None).unwrap();
+        assert_eq!(str_of(&out, "platform"), "meteora_dbc");
+        assert_eq!(
+            str_of(&out, "instruction"),
+            "initialize_virtual_pool_with_token2022"
+        );
+        assert_eq!(str_of(&get(&out, "fields"), "mint"), key(3));
+
+        let data = with_strings(&D_PUMP_CREATE, &["Name", "SYM", "https://x"]);
+        let out = decode(PROGRAM_PUMP_FUN, &data, JsValue::NULL, None).unwrap();
+        assert_eq!(str_of(&out, "instruction"), "create");
+        assert_eq!(str_of(&get(&out, "fields"), "symbol"), "SYM");
+
+        let data = build_pump_fun_create("Name", "SYM", "https://x", &[1; 32], &[2; 32], &[3; 32]);
+        let out = decode(PROGRAM_PUMP_FUN, &data, JsValue::UNDEFINED, None).unwrap();
+        assert_eq!(str_of(&out, "instruction"), "CreateEvent");
+        assert_eq!(str_of(&get(&out, "fields"), "developer"), key(3));
+
+        // Right data, wrong program
+        assert_eq!(
+            err(decode(PROGRAM_MOONSHOT, &data, JsValue::NULL, None)),
+            "no parser for this program and discriminator"
+        );
+        // Accounts-based parser still validates its accounts
+        let data = build_meteora_initialize(false, "Name", "SYM", "https://x");
+        assert_eq!(
+            err(decode(PROGRAM_METEORA_DBC, &data, JsValue::NULL, None)),
+            "accounts must be an array of base58 strings"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn payload_n_skips_the_tag() {
+        let data = [12, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        assert_eq!(payload_n(&data, 1).unwrap(), &data[1..]);
+        assert_eq!(payload_n(&data, 8).unwrap(), [8, 9]);
+        assert_eq!(payload(&data).unwrap(), [8, 9]);
+        assert!(payload_n(&data, 10).unwrap().is_empty());
+        assert_eq!(err(payload_n(&data, 11)), "Data too short");
+        assert_eq!(err(payload_n(&[], 1)), "Data too short");
+    }
+
+    #[wasm_bindgen_test]
+    fn parse_instructions_max_len_boundary() {
+        let batch = |n: usize| {
+            let data = Uint8Array::from(&build_moonshot_trade(false, 1, 2, 0, 50)[..]);
+            let item = Object::new();
+            Reflect::set(&item, &"programId".into(), &PROGRAM_MOONSHOT.into()).unwrap();
+            Reflect::set(&item, &"data".into(), &data).unwrap();
+            let arr = Array::new();
+            for _ in 0..n {
+                arr.push(&item);
+            }
+            JsValue::from(arr)
+        };
+
+        let out = Array::from(&parse_instructions(batch(3), Some(3)).unwrap());
+        assert_eq!(out.length(), 3);
+        assert_eq!(str_of(&out.get(2), "instruction"), "buy");
+        assert_eq!(
+            err(parse_instructions(batch(4), Some(3))),
+            "too many instructions: 4 exceeds maxLen 3"
+        );
+
+        assert_eq!(
+            Array::from(&parse_instructions(batch(256), None).unwrap()).length(),
+            256
+        );
+        assert_eq!(
+            err(parse_instructions(batch(257), None)),
+            "too many instructions: 257 exceeds maxLen 256"
+        );
+
+        assert_eq!(
+            err(parse_instructions(batch(0), Some(0))),
+            "maxLen must be at least 1"
+        );
+        assert_eq!(
+            Array::from(&parse_instructions(batch(0), Some(1)).unwrap()).length(),
+            0
+        );
+
+        // A failing entry becomes { error } without aborting the batch
+        let mixed = Array::from(&batch(1));
+        mixed.push(&Object::new());
+        let out = Array::from(&parse_instructions(mixed.into(), None).unwrap());
+        assert_eq!(str_of(&out.get(1), "error"), "programId must be a string");
+    }
+
+    #[wasm_bindgen_test]
+    fn decode_as_json_round_trips_through_json_parse() {
+        let data = build_pump_fun_curve_state(u64::MAX, 30_000_000_000, 0, 0, 1, true);
+        let json = decode_as_json(PROGRAM_PUMP_FUN, &data, JsValue::NULL).unwrap();
+        assert!(json.contains(&format!("\"{}\"", u64::MAX)), "{}", json);
+
+        let parsed = JSON::parse(&json).unwrap();
+        assert_eq!(str_of(&parsed, "instruction"), "BondingCurve");
+        let fields = get(&parsed, "fields");
+        assert_eq!(
+            str_of(&fields, "virtual_token_reserves"),
+            u64::MAX.to_string()
+        );
+        assert_eq!(str_of(&fields, "virtual_sol_reserves"), "30000000000");
+        assert_eq!(get(&fields, "complete").as_bool(), Some(true));
+
+        // The global setting is restored afterwards
+        let out = decode(PROGRAM_PUMP_FUN, &data, JsValue::NULL, None).unwrap();
+        assert!(get(&get(&out, "fields"), "virtual_token_reserves").is_bigint());
+        assert_eq!(
+            err(decode_as_json(PROGRAM_MOONSHOT, &data, JsValue::NULL)),
+            "no parser for this program and discriminator"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn with_schema_matches_values() {
+        fn check(parser: &str, values: JsValue) {
+            let out = with_schema(parser, values.clone()).unwrap();
+            assert!(Object::is(&get(&out, "values"), &values));
+            let fields = Array::from(&get(&out, "fields"));
+            // Every value key has a schema entry, and its type matches the value
+            assert_eq!(
+                fields.length(),
+                Object::keys(&values.clone().into()).length()
+            );
+            for field in fields.iter() {
+                let value = get(&values, &str_of(&field, "name"));
+                let ok = match str_of(&field, "type").as_str() {
+                    "amount" => value.is_bigint(),
+                    "count" => value.as_f64().is_some(),
+                    "flag" => value.as_bool().is_some(),
+                    "text" => value.is_string(),
+                    // Account-derived pubkeys are null when the parser has no accounts
+                    "pubkey" => value.is_string() || value.is_null(),
+                    other => panic!("unknown type {}", other),
+                };
+                assert!(
+                    ok,
+                    "{}.{} has the wrong type",
+                    parser,
+                    str_of(&field, "name")
+                );
+            }
+        }
+
+        let curve = build_pump_fun_curve_state(1, 2, 3, 4, 5, false);
+        check(
+            "parsePumpFunCurveState",
+            parse_pump_fun_curve_state(&curve, Some(true), None).unwrap(),
+        );
+        let curve = build_pump_fun_curve_state_with_creator(1, 2, false, &[7; 32]);
+        check(
+            "parsePumpFunCurveStateWithCreator",
+            parse_pump_fun_curve_state_with_creator(&curve).unwrap(),
+        );
+        let create =
+            build_pump_fun_create("Name", "SYM", "https://x", &[1; 32], &[2; 32], &[3; 32]);
+        check(
+            "parsePumpFunCreate",
+            parse_pump_fun_create(&create, None, None).unwrap(),
+        );
+        let create = build_boop_create_token(1, "Name", "SYM", "https://x");
+        check(
+            "parseBoopCreateToken",
+            parse_boop_create_token(&create).unwrap(),
+        );
+
+        assert_eq!(
+            err(with_schema("parseBoopBuyToken", JsValue::NULL)),
+            "no schema for parseBoopBuyToken"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn meteora_ambiguous_decode_prefers_plausible_metadata() {
+        // Header-less params whose bytes also decode with the first 8 bytes
+        // ("Name" and its length) taken as a header: that reading yields
+        // name "SYM" and the URI as an over-long symbol.
+        let uri = "https://example.com/meta.json";
+        let raw = with_strings(&[], &["Name", "SYM", uri, ""]);
+        let body = payload(&raw).unwrap();
+        let misread = borsh_decode::<InitializePoolParameters>(body, false).unwrap();
+        assert_eq!(
+            (misread.name.as_str(), misread.symbol.as_str()),
+            ("SYM", uri)
+        );
+
+        let out = with_check(false, || parse_meteora_initialize(&raw, accounts(6))).unwrap();
+        assert_eq!(str_of(&out, "name"), "Name");
+        assert_eq!(str_of(&out, "symbol"), "SYM");
+        assert_eq!(str_of(&out, "uri"), uri);
+
+        // A well-formed header decode still wins
+        let data = with_strings(&D_METEORA_INIT_SPL, &["Name", "SYM", uri]);
+        let out = with_check(false, || parse_meteora_initialize(&data, accounts(6))).unwrap();
+        assert_eq!(str_of(&out, "name"), "Name");
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn expected_size_for_fixed_and_variable_layouts() {
+        let fixed = [
+            (
+                "pump_fun:BondingCurve",
+                build_pump_fun_curve_state(1, 2, 3, 4, 5, false),
+            ),
+            (
+                "pump_fun:BondingCurveWithCreator",
+                build_pump_fun_curve_state_with_creator(1, 2, false, &[7; 32]),
+            ),
+            ("pump_fun:Global", build_pump_fun_global(1, 2, 3, 4, 5)),
+            (
+                "pump_fun:CompleteEvent",
+                build_pump_fun_complete_event(&[1; 32], &[2; 32], &[3; 32], 4),
+            ),
+            (
+                "pump_fun:TradeEvent",
+                build_pump_fun_trade_event(&[1; 32], 2, 3, true, &[4; 32], 5),
+            ),
+            ("boop:buy_token", build_boop_trade(false, 1, 2)),
+            ("moonshot:sell", build_moonshot_trade(true, 1, 2, 0, 3)),
+            (
+                "raydium_launchpad:buy_exact_in",
+                build_launchpad_trade(false, 1, 2, 3),
+            ),
+        ];
+        for (layout, data) in fixed {
+            assert_eq!(expected_size(layout), Some(data.len()), "{}", layout);
+        }
+
+        for layout in [
+            "pump_fun:CreateEvent",
+            "boop:create_token",
+            "moonshot:token_mint",
+        ] {
+            assert_eq!(expected_size(layout), None, "{}", layout);
+        }
+        assert_eq!(expected_size("pump_fun:NoSuchAccount"), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn cursor_sequential_mixed_reads() {
+        let mut data = vec![1, 0x34, 0x12];
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        push_string(&mut data, "SYM");
+        data.extend_from_slice(&[0; 32]);
+        data.extend_from_slice(&[0x80, 0x01, 0xaa, 0xbb]);
+
+        let mut c = Cursor::new(&data);
+        assert!(c.read_bool().unwrap());
+        assert_eq!(c.read_u16().unwrap(), 0x1234);
+        assert_eq!(c.offset(), 3);
+        assert!(c.read_u64().unwrap().is_bigint());
+        assert_eq!(c.read_string().unwrap(), "SYM");
+        assert_eq!(c.read_pubkey().unwrap(), "11111111111111111111111111111111");
+        assert_eq!(c.read_compact_u16().unwrap(), 128);
+        assert_eq!(c.remaining(), 2);
+
+        // A failed read leaves the offset where it was
+        let before = c.offset();
+        assert!(c.read_u32().is_err());
+        assert_eq!(c.offset(), before);
+        assert_eq!(c.read_bytes(2).unwrap(), vec![0xaa, 0xbb]);
+        assert_eq!(c.remaining(), 0);
+        assert!(c.skip(1).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn curve_state_one_byte_short_fails_up_front() {
+        let data = build_pump_fun_curve_state(1, 2, 3, 4, 5, true);
+        let short = &data[..data.len() - 1];
+        let expected = "Curve state too short: 40 bytes after the discriminator, need at least 41";
+        assert_eq!(err(parse_pump_fun_curve_state(short, None, None)), expected);
+        assert_eq!(
+            err(parse_pump_fun_curve_state_with_creator(short)),
+            expected
+        );
+
+        // The minimum itself parses, with or without the trailing creator
+        assert!(parse_pump_fun_curve_state(&data, None, None).is_ok());
+        let out = parse_pump_fun_curve_state_with_creator(&data).unwrap();
+        assert!(get(&out, "creator").is_null());
+    }
+
+    #[wasm_bindgen_test]
+    fn pump_fun_buy_with_fees_bounds_on_max_sol_cost() {
+        let data = build_pump_fun_buy(1_000_000, 1_000_000_001);
+        let out = parse_pump_fun_buy_with_fees(&data, 95, 5).unwrap();
+        assert_eq!(big(&out, "tokenAmount"), 1_000_000);
+        assert_eq!(big(&out, "maxSolCost"), 1_000_000_001);
+        // The fees come on top, so 1_000_000_001 covers at most 990_099_010
+        // through the curve, plus 9_405_941 and 495_050 rounded up
+        assert_eq!(big(&out, "maxNetSolAmount"), 990_099_010);
+        assert_eq!(big(&out, "maxProtocolFee"), 9_405_941);
+        assert_eq!(big(&out, "maxCreatorFee"), 495_050);
+        assert_eq!(str_of(&out, "limitKind"), "max_in");
+
+        // The bound is tight: one more lamport through the curve goes over
+        for max_sol_cost in [1, 99, 1_999, 10_101, 123_456_789] {
+            let data = build_pump_fun_buy(1, max_sol_cost);
+            let out = parse_pump_fun_buy_with_fees(&data, 95, 5).unwrap();
+            let net = big(&out, "maxNetSolAmount");
+            let fees = compute_pump_fun_fees(net, 95, 5, "buy").unwrap();
+            assert!(big(&fees, "userAmount") <= max_sol_cost);
+            let fees = compute_pump_fun_fees(net + 1, 95, 5, "buy").unwrap();
+            assert!(big(&fees, "userAmount") > max_sol_cost);
+        }
+
+        assert_eq!(
+            err(parse_pump_fun_buy_with_fees(&data, 10_001, 0)),
+            "Fee bps must be at most 10000"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn curve_progress_percent() {
+        assert_eq!(
+            progress_percent(42_500_000_000, 85_000_000_000, false).unwrap(),
+            50.0
+        );
+        assert_eq!(progress_percent(0, 85_000_000_000, false).unwrap(), 0.0);
+        // Over-target reserves clamp, and a completed curve is done
+        assert_eq!(
+            progress_percent(90_000_000_000, 85_000_000_000, false).unwrap(),
+            100.0
+        );
+        assert_eq!(progress_percent(1, 85_000_000_000, true).unwrap(), 100.0);
+        assert_eq!(
+            err(progress_percent(1, 0, false)),
+            "Graduation target must be non-zero"
+        );
+        assert_eq!(
+            err(progress_percent(1, 0, true)),
+            "Graduation target must be non-zero"
+        );
+
+        let data = build_pump_fun_curve_state(1, 2, 3, 21_250_000_000, 5, false);
+        let out = parse_pump_fun_curve_state(&data, None, Some(true)).unwrap();
+        assert_eq!(get(&out, "progressPercent").as_f64(), Some(25.0));
+        let out = parse_pump_fun_curve_state(&data, None, None).unwrap();
+        assert!(get(&out, "progressPercent").is_undefined());
+    }
+
+    #[wasm_bindgen_test]
+    fn pump_fun_admin_follows_discriminator_check() {
+        let mut data = build_pump_fun_set_creator([7; 32]);
+        let out = parse_pump_fun_admin(&data, accounts(5), None).unwrap();
+        assert_eq!(str_of(&out, "instruction"), "set_creator");
+        assert_eq!(str_of(&out, "creator"), key(7));
+        assert_eq!(str_of(&out, "bondingCurve"), key(4));
+
+        data[..8].copy_from_slice(&[0xaa; 8]);
+        let named = || parse_pump_fun_admin(&data, accounts(5), Some("set_creator".into()));
+        assert_eq!(err(with_check(true, named)), "discriminator mismatch");
+        let out = with_check(false, named).unwrap();
+        assert_eq!(str_of(&out, "creator"), key(7));
+        // Without a name there is nothing to dispatch on
+        assert!(err(with_check(false, || parse_pump_fun_admin(
+            &data,
+            accounts(5),
+            None
+        )))
+        .contains("not a Pump.fun admin ix"));
+    }
+
+    #[wasm_bindgen_test]
+    fn decode_degrades_unknown_discriminators_on_request() {
+        let mut data = vec![0xaa; 8];
+        data.extend_from_slice(&[1, 2, 3]);
+
+        let out = decode(PROGRAM_PUMP_FUN, &data, JsValue::NULL, Some(true)).unwrap();
+        assert_eq!(str_of(&out, "platform"), "pump_fun");
+        assert!(get(&out, "instruction").is_null());
+        assert_eq!(str_of(&out, "discriminatorHex"), "aaaaaaaaaaaaaaaa");
+        assert_eq!(str_of(&out, "dataHex"), "010203");
+
+        // Strict by default, and unknown programs always throw
+        assert_eq!(
+            err(decode(PROGRAM_PUMP_FUN, &data, JsValue::NULL, None)),
+            "no parser for this program and discriminator"
+        );
+        assert_eq!(
+            err(decode(
+                "11111111111111111111111111111111",
+                &data,
+                JsValue::NULL,
+                Some(true)
+            )),
+            "unsupported program"
+        );
+
+        // Single-byte tags split after one byte
+        let out = decode(PROGRAM_TOKEN, &[0xee, 4, 5], JsValue::NULL, Some(true)).unwrap();
+        assert_eq!(str_of(&out, "platform"), "spl_token");
+        assert_eq!(str_of(&out, "discriminatorHex"), "ee");
+        assert_eq!(str_of(&out, "dataHex"), "0405");
+    }
+
+    #[wasm_bindgen_test]
+    fn looks_like_anchor_matches_the_first_eight_bytes() {
+        let mut data = D_RAY_INIT.to_vec();
+        data.extend_from_slice(&[9; 4]);
+
+        // null falls back to the built-in table
+        assert!(looks_like_anchor(&data, JsValue::NULL).unwrap());
+        assert!(!looks_like_anchor(&[0xaa; 12], JsValue::NULL).unwrap());
+        assert!(!looks_like_anchor(&D_RAY_INIT[..7], JsValue::NULL).unwrap());
+
+        let known = Array::of1(&Uint8Array::from(&[0xaa; 8][..]));
+        assert!(looks_like_anchor(&[0xaa; 12], known.clone().into()).unwrap());
+        assert!(!looks_like_anchor(&data, known.into()).unwrap());
+
+        let short = Array::of1(&Uint8Array::from(&[0xaa; 4][..]));
+        assert_eq!(
+            err(looks_like_anchor(&data, short.into())),
+            "discriminators must be 8 bytes"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn meteora_initialize_parsers_check_account_count_first() {
+        let data = build_meteora_initialize(false, "Short", "SHRT", "https://x");
+        assert_eq!(
+            err(parse_meteora_initialize(&data, accounts(4))),
+            "Meteora initialize needs accounts at indices 2, 3, 5 (got 4)"
+        );
+        assert_eq!(
+            err(parse_meteora_initialize_with_config(&data, accounts(4))),
+            "Meteora initialize needs accounts at indices 0, 2-7 (got 4)"
+        );
+        assert_eq!(
+            err(parse_daos_fun_create(&data, accounts(4))),
+            "Daos.fun create needs accounts at indices 0, 2, 3, 5 (got 4)"
+        );
+        assert!(parse_meteora_initialize_with_config(&data, accounts(8)).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn strict_mode_sets_the_default_for_strict_and_lossy() {
+        let mut data = D_PUMP_CREATE_EVENT.to_vec();
+        data.extend_from_slice(&5u32.to_le_bytes());
+        data.extend_from_slice(b"Na\xffme");
+        let mut data = with_strings(&data, &["SYM", "https://x"]);
+        data.extend_from_slice(&[1; 96]);
+        let create =
+            build_pump_fun_create("Name", "SYM", "https://x", &[1; 32], &[2; 32], &[3; 32]);
+        let create = [&create[..], &[0, 0]].concat();
+
+        set_strict_mode(Some(false));
+        let out = parse_pump_fun_create(&data, None, None).unwrap();
+        assert_eq!(str_of(&out, "name"), "Na\u{fffd}me");
+        assert!(parse_pump_fun_create(&create, None, None).is_ok());
+
+        set_strict_mode(Some(true));
+        assert_eq!(
+            err(parse_pump_fun_create(&data, None, None)),
+            "Invalid UTF-8 at offset 0"
+        );
+        assert_eq!(
+            err(parse_pump_fun_create(&create, None, None)),
+            format!("2 trailing bytes at offset {}", create.len() - 10)
+        );
+        // Per-call flags still win
+        assert!(parse_pump_fun_create(&data, Some(true), None).is_ok());
+        assert!(parse_pump_fun_create(&create, None, Some(false)).is_ok());
+
+        set_strict_mode(None);
+        assert!(parse_pump_fun_create(&data, None, None).is_err());
+        assert!(parse_pump_fun_create(&create, None, None).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn spl_memo_decodes_text_lossily() {
+        assert_eq!(str_of(&parse_spl_memo(b"gm").unwrap(), "memo"), "gm");
+        assert_eq!(
+            str_of(&parse_spl_memo("🚀 launch".as_bytes()).unwrap(), "memo"),
+            "🚀 launch"
+        );
+        assert_eq!(
+            str_of(&parse_spl_memo(b"a\xffb").unwrap(), "memo"),
+            "a\u{fffd}b"
+        );
+
+        let out = decode(PROGRAM_MEMO, b"gm", JsValue::NULL, None).unwrap();
+        assert_eq!(str_of(&out, "platform"), "spl_memo");
+        assert_eq!(str_of(&get(&out, "fields"), "memo"), "gm");
+    }
+
+    #[wasm_bindgen_test]
+    fn discriminator_returns_the_header_bytes() {
+        let data = [&D_RAY_INIT[..], &[1, 2, 3]].concat();
+        assert_eq!(discriminator(&data).unwrap(), D_RAY_INIT);
+        assert_eq!(discriminator(&D_RAY_INIT).unwrap(), D_RAY_INIT);
+        assert_eq!(err(discriminator(&D_RAY_INIT[..7])), "Data too short");
+    }
+
+    #[wasm_bindgen_test]
+    fn graduation_threshold_override_changes_progress() {
+        let data = build_pump_fun_curve_state(1, 2, 3, 21_250_000_000, 5, false);
+        let percent = || {
+            get(
+                &parse_pump_fun_curve_state(&data, None, Some(true)).unwrap(),
+                "progressPercent",
+            )
+            .as_f64()
+        };
+        assert_eq!(percent(), Some(25.0));
+
+        set_graduation_threshold("pump_fun", 42_500_000_000).unwrap();
+        assert_eq!(percent(), Some(50.0));
+        set_graduation_threshold("pump_fun", 10_000_000_000).unwrap();
+        assert_eq!(percent(), Some(100.0));
+        assert_eq!(
+            err(set_graduation_threshold("pump_fun", 0)),
+            "Graduation threshold must be non-zero"
+        );
+        assert_eq!(graduation_threshold("pump_fun"), Some(10_000_000_000));
+        assert_eq!(graduation_threshold("boop"), None);
+
+        set_graduation_threshold("pump_fun", 85_000_000_000).unwrap();
+        assert_eq!(percent(), Some(25.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn pump_swap_create_pool_resolves_accounts() {
+        let data =
+            build_pump_swap_create_pool(1, 206_900_000_000_000, 84_990_359_000, Some([9; 32]));
+        let out = parse_pump_swap_create_pool(&data, accounts(6)).unwrap();
+        assert_eq!(get(&out, "index").as_f64(), Some(1.0));
+        assert_eq!(big(&out, "baseAmountIn"), 206_900_000_000_000);
+        assert_eq!(big(&out, "quoteAmountIn"), 84_990_359_000);
+        assert_eq!(str_of(&out, "coinCreator"), key(9));
+        assert_eq!(str_of(&out, "pool"), key(0));
+        assert_eq!(str_of(&out, "baseMint"), key(3));
+        assert_eq!(str_of(&out, "lpMint"), key(5));
+
+        // Older layout without coin_creator
+        let data = build_pump_swap_create_pool(1, 2, 3, None);
+        let out = parse_pump_swap_create_pool(&data, accounts(6)).unwrap();
+        assert!(get(&out, "coinCreator").is_null());
+        assert_eq!(
+            err(parse_pump_swap_create_pool(&data, accounts(5))),
+            "missing account at index 5"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn account_validation_rejects_malformed_keys() {
+        let data = build_meteora_initialize(false, "Name", "SYM", "https://x");
+        let accs = Array::from(&accounts(6));
+        // A 64-byte signature passed where a pubkey belongs
+        accs.set(3, JsValue::from_str(&bs58::encode([7; 64]).into_string()));
+
+        // Off by default: any string is passed through
+        assert!(parse_meteora_initialize(&data, accs.clone().into()).is_ok());
+
+        set_account_validation(true);
+        assert!(parse_meteora_initialize(&data, accounts(6)).is_ok());
+        assert_eq!(
+            err(parse_meteora_initialize(&data, accs.clone().into())),
+            "account at index 3 is not a valid pubkey: pubkey must be 32 bytes (got 64)"
+        );
+        accs.set(3, JsValue::from_str("0OIl"));
+        assert!(err(parse_meteora_initialize(&data, accs.into()))
+            .starts_with("account at index 3 is not a valid pubkey: Invalid Base58"));
+        set_account_validation(false);
+    }
+
+    #[wasm_bindgen_test]
+    fn pump_fun_creator_info_handles_both_layouts() {
+        let new = build_pump_fun_curve_state_with_creator(1, 2, false, &[0xcc; 32]);
+        let out = parse_pump_fun_creator_info(&new, accounts(10), None).unwrap();
+        assert_eq!(str_of(&out, "creator"), key(0xcc));
+        assert_eq!(str_of(&out, "creatorVault"), key(9));
+        // `sell` puts the vault at index 8
+        let out = parse_pump_fun_creator_info(&new, accounts(10), Some(8)).unwrap();
+        assert_eq!(str_of(&out, "creatorVault"), key(8));
+        let out = parse_pump_fun_creator_info(&new, JsValue::NULL, None).unwrap();
+        assert_eq!(str_of(&out, "creator"), key(0xcc));
+        assert!(get(&out, "creatorVault").is_null());
+
+        // Old curves have no creator, so no vault is looked up
+        let old = build_pump_fun_curve_state(1, 2, 3, 4, 5, false);
+        let out = parse_pump_fun_creator_info(&old, accounts(2), None).unwrap();
+        assert!(get(&out, "creator").is_null());
+        assert!(get(&out, "creatorVault").is_null());
+    }
+
+    #[wasm_bindgen_test]
+    fn decode_transaction_resolves_account_indices() {
+        let keys = Array::from(&accounts(6));
+        keys.push(&JsValue::from_str(PROGRAM_MEMO));
+        keys.push(&JsValue::from_str(PROGRAM_METEORA_DBC));
+        let ix = |program: u32, accs: &[u8], data: &[u8]| {
+            let item = Object::new();
+            Reflect::set(&item, &"programIdIndex".into(), &program.into()).unwrap();
+            Reflect::set(&item, &"accounts".into(), &Uint8Array::from(accs)).unwrap();
+            Reflect::set(&item, &"data".into(), &Uint8Array::from(data)).unwrap();
+            JsValue::from(item)
+        };
+        let create = build_meteora_initialize(false, "Name", "SYM", "https://x");
+        let ixs = Array::of3(
+            &ix(6, &[], b"gm"),
+            // Reversed, so the mint at position 3 is key 2
+            &ix(7, &[5, 4, 3, 2, 1, 0], &create),
+            &ix(7, &[5, 4, 3, 2, 1, 9], &create),
+        );
+
+        let out = Array::from(&decode_transaction(keys.clone().into(), ixs.into()).unwrap());
+        assert_eq!(out.length(), 3);
+        assert_eq!(str_of(&get(&out.get(0), "fields"), "memo"), "gm");
+        let fields = get(&out.get(1), "fields");
+        assert_eq!(str_of(&fields, "name"), "Name");
+        assert_eq!(str_of(&fields, "mint"), key(2));
+        // An out-of-range index fails only its own entry
+        assert_eq!(str_of(&out.get(2), "error"), "missing account at index 9");
+
+        let bad = Array::of1(&ix(6, &[], b"gm"));
+        Reflect::set(&bad.get(0), &"accounts".into(), &"0".into()).unwrap();
+        let out = Array::from(&decode_transaction(keys.into(), bad.into()).unwrap());
+        assert_eq!(
+            str_of(&out.get(0), "error"),
+            "accounts must be an array of indices"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn raydium_cpmm_swaps_in_both_directions() {
+        let data = build_cpmm_swap(false, 1_000, 900);
+        let out = parse_raydium_cpmm_swap_base_input(&data, accounts(12)).unwrap();
+        assert_eq!(big(&out, "amountIn"), 1_000);
+        assert_eq!(big(&out, "amountOut"), 900);
+        assert_eq!(str_of(&out, "poolState"), key(3));
+        assert_eq!(str_of(&out, "inputMint"), key(10));
+        assert_eq!(str_of(&out, "outputMint"), key(11));
+        // Exact input: the fixed amount is the input, the bound the output
+        assert_eq!(str_of(&out, "side"), "sell");
+        assert_eq!(big(&out, "amount"), 1_000);
+        assert_eq!(big(&out, "limitAmount"), 900);
+        assert_eq!(str_of(&out, "limitKind"), "min_out");
+        assert!(parse_raydium_cpmm_swap_base_output(&data, accounts(12)).is_err());
+
+        // Spending wrapped SOL is a buy
+        let accs = Array::from(&accounts(12));
+        accs.set(10, JsValue::from_str(MINT_WSOL));
+        let data = build_cpmm_swap(true, 1_100, 1_000);
+        let out = parse_raydium_cpmm_swap_base_output(&data, accs.into()).unwrap();
+        assert_eq!(big(&out, "amountIn"), 1_100);
+        assert_eq!(big(&out, "amountOut"), 1_000);
+        // Exact output: the fixed amount is the output, the bound the input
+        assert_eq!(str_of(&out, "side"), "buy");
+        assert_eq!(big(&out, "amount"), 1_000);
+        assert_eq!(big(&out, "limitAmount"), 1_100);
+        assert_eq!(str_of(&out, "limitKind"), "max_in");
+        assert_eq!(
+            err(parse_raydium_cpmm_swap_base_output(&data, accounts(11))),
+            "missing account at index 11"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn pubkey_as_bytes_returns_the_raw_key() {
+        let data = build_pump_fun_curve_state_with_creator(1, 2, false, &[0xcc; 32]);
+        let out = parse_pump_fun_curve_state_with_creator(&data).unwrap();
+        assert_eq!(str_of(&out, "creator"), key(0xcc));
+
+        set_pubkey_as_bytes(true);
+        let out = parse_pump_fun_creator_info(&data, accounts(10), None).unwrap();
+        set_pubkey_as_bytes(false);
+        let creator = get(&out, "creator").dyn_into::<Uint8Array>().unwrap();
+        assert_eq!(creator.to_vec(), [0xcc; 32]);
+        // Keys from `accounts` stay Base58
+        assert_eq!(str_of(&out, "creatorVault"), key(9));
+    }
+
+    #[wasm_bindgen_test]
+    fn launchpad_pool_state_reports_progress() {
+        let pool = |status: u8, real_quote: u64, goal: u64| {
+            let mut data = D_ACC_POOL_STATE.to_vec();
+            data.extend_from_slice(&[0; 8 + 1]); // epoch, auth_bump
+            data.extend_from_slice(&[status, 6, 9, 1]);
+            data.extend_from_slice(&[0; 5 * 8]);
+            data.extend_from_slice(&real_quote.to_le_bytes());
+            data.extend_from_slice(&goal.to_le_bytes());
+            data.extend_from_slice(&[0; 8 * 8 + 4 * 32]);
+            parse_launchpad_pool_state(&data, None, None).unwrap()
+        };
+        let progress = |out: &JsValue| get(out, "progressPercent").as_f64();
+        let complete = |out: &JsValue| get(out, "isComplete").as_bool().unwrap();
+
+        let early = pool(0, 8_500_000_000, 85_000_000_000);
+        assert_eq!(progress(&early), Some(10.0));
+        assert!(!complete(&early));
+
+        let near = pool(0, 84_150_000_000, 85_000_000_000);
+        assert_eq!(progress(&near), Some(99.0));
+        assert!(!complete(&near));
+
+        // Reaching the goal completes even before the status moves on
+        let reached = pool(0, 85_000_000_000, 85_000_000_000);
+        assert_eq!(progress(&reached), Some(100.0));
+        assert!(complete(&reached));
+
+        // Migrate (1) and trade (2) are complete regardless of real_quote
+        for status in [1, 2] {
+            let done = pool(status, 1, 85_000_000_000);
+            assert_eq!(progress(&done), Some(100.0));
+            assert!(complete(&done));
+        }
+
+        assert!(get(&pool(0, 0, 0), "progressPercent").is_null());
+    }
+
+    #[wasm_bindgen_test]
+    fn fields_subset_returns_only_requested_keys() {
+        let keys = |out: &JsValue| {
+            let mut keys: Vec<String> = Object::keys(&Object::from(out.clone()))
+                .iter()
+                .filter_map(|k| k.as_string())
+                .collect();
+            keys.sort();
+            keys
+        };
+        let wanted = |names: &[&str]| Some(names.iter().map(|s| s.to_string()).collect());
+
+        let event = build_pump_fun_trade_event(&[1; 32], 1_500_000_000, 7, true, &[2; 32], 9);
+        let out = parse_pump_fun_trade_event(&event, wanted(&["mint", "solAmount"])).unwrap();
+        assert_eq!(keys(&out), ["mint", "solAmount"]);
+        assert_eq!(str_of(&out, "mint"), key(1));
+        assert_eq!(big(&out, "solAmount"), 1_500_000_000);
+        assert_eq!(
+            keys(&parse_pump_fun_trade_event(&event, None).unwrap()).len(),
+            9
+        );
+        // Unknown names are ignored rather than rejected
+        let out = parse_pump_fun_trade_event(&event, wanted(&["nope"])).unwrap();
+        assert!(keys(&out).is_empty());
+
+        let mut pool = D_ACC_POOL_STATE.to_vec();
+        pool.extend_from_slice(&[0; 8 + 5 + 15 * 8]);
+        pool.extend_from_slice(&[4; 4 * 32]);
+        let out =
+            parse_launchpad_pool_state(&pool, None, wanted(&["quoteMint", "isComplete"])).unwrap();
+        assert_eq!(keys(&out), ["isComplete", "quoteMint"]);
+        assert_eq!(str_of(&out, "quoteMint"), key(4));
+    }
+
+    #[wasm_bindgen_test]
+    fn pump_fun_create_and_buy_with_and_without_buy() {
+        let create = with_strings(&D_PUMP_CREATE, &["Name", "SYM", "https://x"]);
+        let buy = build_pump_fun_buy(35_000_000, 1_500_000_000);
+
+        let out = parse_pump_fun_create_and_buy(&create, Some(buy.clone())).unwrap();
+        assert_eq!(str_of(&out, "name"), "Name");
+        assert_eq!(str_of(&out, "uri"), "https://x");
+        assert!(get(&out, "developer").is_null());
+        let initial = get(&out, "initialBuy");
+        assert_eq!(big(&initial, "tokenAmount"), 35_000_000);
+        assert_eq!(big(&initial, "maxSolCost"), 1_500_000_000);
+
+        let out = parse_pump_fun_create_and_buy(&create, None).unwrap();
+        assert_eq!(str_of(&out, "symbol"), "SYM");
+        assert!(get(&out, "initialBuy").is_null());
+
+        // Newer creates carry the creator after the strings
+        let with_creator = [&create[..], &[5; 32]].concat();
+        let out = parse_pump_fun_create_and_buy(&with_creator, None).unwrap();
+        assert_eq!(str_of(&out, "developer"), key(5));
+
+        // A sell (or any non-buy) passed as the buy is rejected
+        let mut sell = buy;
+        sell[..8].copy_from_slice(&D_SELL);
+        assert!(parse_pump_fun_create_and_buy(&create, Some(sell)).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn pump_fun_collect_creator_fee_resolves_accounts() {
+        let out =
+            parse_pump_fun_collect_creator_fee(&D_PUMP_COLLECT_CREATOR_FEE, accounts(5)).unwrap();
+        assert_eq!(str_of(&out, "instruction"), "collect_creator_fee");
+        assert_eq!(str_of(&out, "creator"), key(0));
+        assert_eq!(str_of(&out, "creatorVault"), key(1));
+        assert_eq!(
+            err(parse_pump_fun_collect_creator_fee(
+                &D_PUMP_COLLECT_CREATOR_FEE,
+                accounts(1)
+            )),
+            "missing account at index 1"
+        );
+        assert!(parse_pump_fun_collect_creator_fee(&D_PUMP_SET_CREATOR, accounts(5)).is_err());
+
+        let out = decode(
+            PROGRAM_PUMP_FUN,
+            &D_PUMP_COLLECT_CREATOR_FEE,
+            accounts(2),
+            None,
+        )
+        .unwrap();
+        assert_eq!(str_of(&out, "instruction"), "collect_creator_fee");
+    }
+
+    #[wasm_bindgen_test]
+    fn launchpad_trade_event_reads_direction() {
+        let data = build_launchpad_trade_event(&[1; 32], 300, 10_000, true, 110, 2_300);
+        let out = parse_launchpad_trade_event(&data).unwrap();
+        assert_eq!(str_of(&out, "poolState"), key(1));
+        assert_eq!(get(&out, "isBuy").as_bool(), Some(true));
+        assert_eq!(big(&out, "amountIn"), 300);
+        assert_eq!(big(&out, "amountOut"), 10_000);
+        assert_eq!(big(&out, "realBaseAfter"), 110);
+        assert_eq!(big(&out, "realQuoteAfter"), 2_300);
+
+        let data = build_launchpad_trade_event(&[1; 32], 10_000, 300, false, 120, 2_000);
+        let out = parse_launchpad_trade_event(&data).unwrap();
+        assert_eq!(get(&out, "isBuy").as_bool(), Some(false));
+        assert!(parse_launchpad_trade_event(&data[..data.len() - 1]).is_err());
+
+        // Same discriminator as the Pump.fun event; the program picks the parser
+        let out = decode(PROGRAM_RAYDIUM_LAUNCHPAD, &data, JsValue::NULL, None).unwrap();
+        assert_eq!(str_of(&out, "platform"), "raydium_launchpad");
+        assert_eq!(str_of(&out, "instruction"), "TradeEvent");
+    }
+
+    #[wasm_bindgen_test]
+    fn diff_curve_state_between_snapshots() {
+        let before = build_pump_fun_curve_state(1_000_000_000_000, 30_000_000_000, 0, 0, 0, false);
+        let after = build_pump_fun_curve_state(600_000_000_000, 50_000_000_000, 0, 0, 0, false);
+        let before = parse_pump_fun_curve_state(&before, None, None).unwrap();
+        let after = parse_pump_fun_curve_state(&after, None, None).unwrap();
+        let signed = |out: &JsValue, field: &str| {
+            String::from(BigInt::from(get(out, field)).to_string(10).unwrap())
+        };
+
+        let out = diff_curve_state(before.clone(), after.clone()).unwrap();
+        assert_eq!(signed(&out, "deltaSol"), "20000000000");
+        assert_eq!(signed(&out, "deltaToken"), "-400000000000");
+        assert_eq!(get(&out, "priceBefore").as_f64(), Some(0.03));
+        assert_eq!(get(&out, "priceAfter").as_f64(), Some(50.0 / 600.0));
+
+        // Reversed, the deltas flip sign
+        let out = diff_curve_state(after, before).unwrap();
+        assert_eq!(signed(&out, "deltaSol"), "-20000000000");
+
+        // Snapshots taken under setBigIntAsString parse the same
+        let strings = Object::new();
+        Reflect::set(&strings, &"virtual_sol_reserves".into(), &"30".into()).unwrap();
+        Reflect::set(&strings, &"virtual_token_reserves".into(), &"0".into()).unwrap();
+        let out = diff_curve_state(strings.clone().into(), strings.into()).unwrap();
+        assert_eq!(signed(&out, "deltaSol"), "0");
+        assert!(get(&out, "priceBefore").is_null());
+
+        assert_eq!(
+            err(diff_curve_state(Object::new().into(), Object::new().into())),
+            "virtual_sol_reserves must be a u64 (BigInt, string or integer)"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn set_field_names_the_key_on_failure() {
+        let obj = JsValue::from(Object::new());
+        set_field(&obj, "mint", &JsValue::from_str("abc")).unwrap();
+        assert_eq!(str_of(&obj, "mint"), "abc");
+
+        // A frozen object refuses the write
+        let frozen = JsValue::from(Object::freeze(&Object::new()));
+        assert_eq!(
+            err(set_field(&frozen, "mint", &JsValue::TRUE)),
+            "failed to set field mint"
+        );
+        // Writing to a non-object throws, and the key is still named
+        assert!(err(set_field(&JsValue::NULL, "mint", &JsValue::TRUE))
+            .starts_with("failed to set field mint"));
+    }
+
+    #[wasm_bindgen_test]
+    fn moonshot_migration_target_is_named() {
+        let data = build_moonshot_curve(&[1; 32], 1_000, 800, 345_000_000_000, 1);
+        let out = parse_moonshot_curve(&data).unwrap();
+        assert_eq!(str_of(&out, "mint"), key(1));
+        assert_eq!(big(&out, "marketcapThreshold"), 345_000_000_000);
+        assert_eq!(str_of(&out, "migrationTarget"), "Meteora");
+        let mut unknown = data.clone();
+        *unknown.last_mut().unwrap() = 2;
+        assert_eq!(
+            err(parse_moonshot_curve(&unknown)),
+            "Unknown migration target"
+        );
+
+        let mint = build_moonshot_token_mint_full("Moon", "MOON", "https://x", 1, 0);
+        let out = parse_moonshot_token_mint(&mint, None).unwrap();
+        assert_eq!(str_of(&out, "migrationTarget"), "Raydium");
+        // Older mints stop after the uri and have no target
+        let mint = build_moonshot_token_mint("Moon", "MOON", "https://x");
+        let out = parse_moonshot_token_mint(&mint, None).unwrap();
+        assert!(get(&out, "migrationTarget").is_undefined());
+    }
+
+    #[wasm_bindgen_test]
+    fn parse_account_dispatches_on_account_discriminator() {
+        let curve = build_pump_fun_curve_state_with_creator(1, 2, false, &[0xcc; 32]);
+        let out = parse_account(PROGRAM_PUMP_FUN, &curve).unwrap();
+        assert_eq!(str_of(&out, "platform"), "pump_fun");
+        assert_eq!(str_of(&out, "account"), "BondingCurve");
+        assert_eq!(str_of(&get(&out, "fields"), "creator"), key(0xcc));
+
+        let mut pool = D_ACC_POOL_STATE.to_vec();
+        pool.extend_from_slice(&[0; 8 + 5 + 15 * 8]);
+        pool.extend_from_slice(&[4; 4 * 32]);
+        let out = parse_account(PROGRAM_RAYDIUM_LAUNCHPAD, &pool).unwrap();
+        assert_eq!(str_of(&out, "platform"), "raydium_launchpad");
+        assert_eq!(str_of(&out, "account"), "PoolState");
+        assert_eq!(str_of(&get(&out, "fields"), "quoteMint"), key(4));
+
+        // The owning program must match, and instruction data is not an account
+        assert_eq!(
+            err(parse_account(PROGRAM_PUMP_FUN, &pool)),
+            "no account parser for this program and discriminator"
+        );
+        let buy = build_pump_fun_buy(1, 2);
+        assert!(parse_account(PROGRAM_PUMP_FUN, &buy).is_err());
+
+        // decode falls back to the account table
+        let out = decode(PROGRAM_PUMP_FUN, &curve, JsValue::NULL, None).unwrap();
+        assert_eq!(str_of(&out, "instruction"), "BondingCurve");
+    }
+
+    #[wasm_bindgen_test]
+    fn short_pubkey_reads_name_offset_and_remaining() {
+        let buf = [7u8; 40];
+        let mut off = 0;
+        assert_eq!(read_pubkey(&buf, &mut off).unwrap(), key(7));
+        assert_eq!(off, 32);
+        assert_eq!(
+            err(read_pubkey(&buf, &mut off)),
+            "expected 32-byte pubkey at offset 32, only 8 bytes remain"
+        );
+        assert_eq!(off, 32);
+
+        let mut off = 50;
+        assert_eq!(
+            err(read_pubkey_js(&buf, &mut off)),
+            "expected 32-byte pubkey at offset 50, only 0 bytes remain"
+        );
+
+        // A curve account cut inside its creator field
+        let curve = build_pump_fun_curve_state_with_creator(1, 2, false, &[0xcc; 32]);
+        let mut off = CURVE_STATE_MIN_LEN;
+        assert_eq!(
+            err(read_pubkey(&curve[8..curve.len() - 1], &mut off)),
+            format!(
+                "expected 32-byte pubkey at offset {}, only 31 bytes remain",
+                CURVE_STATE_MIN_LEN
+            )
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn pump_swap_liquidity_names_the_bounds() {
+        let data = build_pump_swap_liquidity(false, 1_000, 2_000, 3_000);
+        let out = parse_pump_swap_deposit(&data, accounts(6)).unwrap();
+        assert_eq!(big(&out, "lpTokenAmount"), 1_000);
+        assert_eq!(big(&out, "maxBaseAmountIn"), 2_000);
+        assert_eq!(big(&out, "maxQuoteAmountIn"), 3_000);
+        assert_eq!(str_of(&out, "pool"), key(0));
+        assert_eq!(str_of(&out, "lpMint"), key(5));
+        assert!(parse_pump_swap_withdraw(&data, accounts(6)).is_err());
+
+        let data = build_pump_swap_liquidity(true, 1_000, 2_000, 3_000);
+        let out = parse_pump_swap_withdraw(&data, accounts(6)).unwrap();
+        assert_eq!(big(&out, "minBaseAmountOut"), 2_000);
+        assert_eq!(big(&out, "minQuoteAmountOut"), 3_000);
+        assert_eq!(str_of(&out, "user"), key(2));
+        assert_eq!(
+            err(parse_pump_swap_withdraw(&data, accounts(5))),
+            "missing account at index 5"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn pubkey_encoding_switches_between_base58_and_base64() {
+        let data = build_pump_fun_curve_state_with_creator(1, 2, false, &[0xcc; 32]);
+        let creator = || {
+            str_of(
+                &parse_pump_fun_curve_state_with_creator(&data).unwrap(),
+                "creator",
+            )
+        };
+        assert_eq!(creator(), key(0xcc));
+
+        set_pubkey_encoding("base64").unwrap();
+        assert_eq!(creator(), "zMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMw=");
+        // Keys from `accounts` are returned as given
+        let out = parse_pump_fun_creator_info(&data, accounts(10), None).unwrap();
+        assert_eq!(str_of(&out, "creatorVault"), key(9));
+        set_pubkey_encoding("base58").unwrap();
+        assert_eq!(creator(), key(0xcc));
+
+        assert_eq!(
+            err(set_pubkey_encoding("hex")),
+            "encoding must be \"base58\" or \"base64\""
+        );
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"a"), "YQ==");
+        assert_eq!(encode_base64(b"ab"), "YWI=");
+    }
+
+    #[wasm_bindgen_test]
+    fn meteora_damm_swap_event_reads_amounts_and_fees() {
+        let data = build_meteora_damm_swap_event(1_000, 35_000, 25, 5, 1);
+        let out = parse_meteora_damm_swap_event(&data).unwrap();
+        assert_eq!(big(&out, "inAmount"), 1_000);
+        assert_eq!(big(&out, "outAmount"), 35_000);
+        assert_eq!(big(&out, "tradeFee"), 25);
+        assert_eq!(big(&out, "protocolFee"), 5);
+        assert_eq!(big(&out, "hostFee"), 1);
+        assert!(parse_meteora_damm_swap_event(&data[..data.len() - 8]).is_err());
+
+        let out = decode(PROGRAM_METEORA_DAMM, &data, JsValue::NULL, None).unwrap();
+        assert_eq!(str_of(&out, "instruction"), "Swap");
+    }
+
+    #[wasm_bindgen_test]
+    fn unsupported_programs_are_rejected_before_decoding() {
+        assert!(is_supported_program(PROGRAM_PUMP_FUN));
+        assert!(is_supported_program(PROGRAM_MEMO));
+        assert!(is_supported_program(PROGRAM_METEORA_DAMM));
+        assert!(!is_supported_program("11111111111111111111111111111111"));
+        assert!(!is_supported_program(""));
+
+        // Even data any parser would choke on gets the program error
+        let system = "11111111111111111111111111111111";
+        assert_eq!(
+            err(decode(system, &[], JsValue::NULL, None)),
+            "unsupported program"
+        );
+        assert_eq!(err(parse_account(system, &[])), "unsupported program");
+        assert_eq!(
+            err(parse_account(PROGRAM_PUMP_FUN, &[])),
+            "no account parser for this program and discriminator"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn market_cap_matches_worked_examples() {
+        // Pump.fun's standard launch curve (Global's initial virtual reserves
+        // and supply): 30 SOL / 1.073B tokens, 1B supply at 6 decimals
+        assert_eq!(
+            compute_market_cap(
+                30_000_000_000,
+                1_073_000_000_000_000,
+                1_000_000_000_000_000,
+                6,
+                150.0
+            )
+            .unwrap(),
+            "4193.85"
+        );
+        // The same curve at graduation, once the 793.1M curve tokens are sold:
+        // 279.9M virtual tokens left and, from k = 30e9 * 1.073e15, ~115 SOL
+        assert_eq!(
+            compute_market_cap(
+                115_005_359_056,
+                279_900_000_000_000,
+                1_000_000_000_000_000,
+                6,
+                150.0
+            )
+            .unwrap(),
+            "61632.03"
+        );
+        // Decimals cancel out: the same ratio at 9 decimals prices identically
+        assert_eq!(
+            compute_market_cap(
+                30_000_000_000,
+                1_073_000_000_000_000_000,
+                1_000_000_000_000_000_000,
+                9,
+                150.0
+            )
+            .unwrap(),
+            "4193.85"
+        );
+        // Token at exactly 1 SOL: 10 SOL / 10 tokens, 100 supply
+        assert_eq!(
+            compute_market_cap(10_000_000_000, 10_000_000, 100_000_000, 6, 2.5).unwrap(),
+            "250.00"
+        );
+        assert_eq!(
+            compute_market_cap(0, 1_000, 1_000, 6, 150.0).unwrap(),
+            "0.00"
+        );
+
+        assert_eq!(
+            err(compute_market_cap(1, 0, 1_000, 6, 150.0)),
+            "Token reserves must be non-zero"
+        );
+        assert_eq!(
+            err(compute_market_cap(1, 1, 1, 6, f64::NAN)),
+            "SOL price must be a non-negative number"
+        );
+        // `decimals` is unused, so any value gives the same cap
+        assert_eq!(compute_market_cap(2, 1, 1, 255, 1e9).unwrap(), "2.00");
+    }
+
+    #[wasm_bindgen_test]
+    fn launchpad_trades_surface_optional_share_fee_receiver() {
+        let buy = build_launchpad_trade(false, 1_000, 2_000, 50);
+        let sell = build_launchpad_trade(true, 3_000, 4_000, 0);
+
+        // Without a referral the trade stops at the IDL's 15 accounts
+        let out = parse_raydium_launchpad_buy_exact_in(&buy, accounts(15), None).unwrap();
+        assert!(get(&out, "shareFeeReceiver").is_null());
+        assert_eq!(big(&out, "shareFeeRate"), 50);
+        assert_eq!(str_of(&out, "platformConfig"), key(3));
+
+        // A 16th remaining account is the receiver
+        let out = parse_raydium_launchpad_buy_exact_in(&buy, accounts(16), None).unwrap();
+        assert_eq!(str_of(&out, "shareFeeReceiver"), key(15));
+        let out = parse_raydium_launchpad_sell_exact_in(&sell, accounts(17), None).unwrap();
+        assert_eq!(str_of(&out, "shareFeeReceiver"), key(15));
+        assert_eq!(big(&out, "shareFeeRate"), 0);
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn find_route_matches_a_linear_scan() {
+        let linear = |program_id: &str, data: &[u8]| {
+            DECODE_ROUTES
+                .iter()
+                .find(|r| r.program_id == program_id && data.starts_with(r.discriminator))
+        };
+        let same = |a: Option<&DecodeRoute>, b: Option<&DecodeRoute>| match (a, b) {
+            // `DECODE_ROUTES` is a const, so compare entries rather than addresses
+            (Some(a), Some(b)) => (a.platform, a.instruction) == (b.platform, b.instruction),
+            (None, None) => true,
+            _ => false,
+        };
+
+        for r in DECODE_ROUTES {
+            // Exact discriminator, with a payload, and one byte short of it
+            let mut data = r.discriminator.to_vec();
+            for probe in [&data[..], &[&data[..], &[0xff; 40][..]].concat()[..]] {
+                assert!(
+                    same(find_route(r.program_id, probe), linear(r.program_id, probe)),
+                    "{}:{}",
+                    r.platform,
+                    r.instruction
+                );
+            }
+            if data.pop().is_some() {
+                assert!(same(
+                    find_route(r.program_id, &data),
+                    linear(r.program_id, &data)
+                ));
+            }
+            assert!(find_route("11111111111111111111111111111111", r.discriminator).is_none());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn pool_account_per_platform() {
+        let pool = |program_id: &str, data: Option<&[u8]>| {
+            extract_pool_account(program_id, accounts(24), data.map(<[u8]>::to_vec)).unwrap()
+        };
+        let trade = [0xab; 8];
+
+        // Trade defaults, with and without instruction data
+        for (program_id, index) in [
+            (PROGRAM_PUMP_FUN, 3),
+            (PROGRAM_RAYDIUM_LAUNCHPAD, 4),
+            (PROGRAM_MOONSHOT, 2),
+            (PROGRAM_BOOP, 1),
+            (PROGRAM_METEORA_DBC, 2),
+            (PROGRAM_PUMP_SWAP, 0),
+            (PROGRAM_RAYDIUM_CPMM, 3),
+        ] {
+            assert_eq!(pool(program_id, None), key(index), "{}", program_id);
+            assert_eq!(pool(program_id, Some(&trade)), key(index), "{}", program_id);
+        }
+
+        // Creates and migrations put the pool elsewhere
+        assert_eq!(pool(PROGRAM_PUMP_FUN, Some(&D_PUMP_CREATE)), key(2));
+        assert_eq!(pool(PROGRAM_RAYDIUM_LAUNCHPAD, Some(&D_RAY_INIT)), key(5));
+        assert_eq!(
+            pool(PROGRAM_RAYDIUM_LAUNCHPAD, Some(&D_RAY_MIGRATE_TO_AMM)),
+            key(23)
+        );
+        assert_eq!(
+            pool(PROGRAM_BOOP, Some(&D_BOOP_CREATE_RAYDIUM_POOL)),
+            key(8)
+        );
+        assert_eq!(pool(PROGRAM_METEORA_DBC, Some(&D_METEORA_INIT_T22)), key(5));
+
+        assert_eq!(
+            err(extract_pool_account(PROGRAM_MEMO, accounts(24), None)),
+            "unsupported program"
+        );
+        assert_eq!(
+            err(extract_pool_account(PROGRAM_PUMP_FUN, accounts(3), None)),
+            "missing account at index 3"
+        );
+    }
+
+    /// Before/after timing of route resolution: every `DECODE_ROUTES` entry
+    /// looked up `ROUNDS` times through `find_route` and through the linear
+    /// `find` it replaced. Only the lookup is timed, since decoding the
+    /// payload costs far more than either. The numbers are logged, not
+    /// asserted, as they depend on the machine; run with `--release` for
+    /// representative ones, since unoptimized builds don't inline the index's
+    /// closures and can make it the slower of the two.
+    #[wasm_bindgen_test]
+    fn find_route_benchmark_against_linear_scan() {
+        const ROUNDS: usize = 10_000;
+        let linear = |program_id: &str, data: &[u8]| {
+            DECODE_ROUTES
+                .iter()
+                .find(|r| r.program_id == program_id && data.starts_with(r.discriminator))
+        };
+        let probes: Vec<(&str, Vec<u8>)> = DECODE_ROUTES
+            .iter()
+            .map(|r| (r.program_id, [r.discriminator, &[0xff; 40][..]].concat()))
+            .collect();
+        let time = |lookup: &dyn Fn(&str, &[u8]) -> Option<&'static DecodeRoute>| {
+            let start = js_sys::Date::now();
+            for _ in 0..ROUNDS {
+                for (program_id, data) in &probes {
+                    std::hint::black_box(lookup(std::hint::black_box(program_id), data));
+                }
+            }
+            js_sys::Date::now() - start
+        };
+
+        // Build the index outside the timed loop
+        assert!(find_route(PROGRAM_PUMP_FUN, &D_PUMP_CREATE).is_some());
+        let indexed = time(&find_route);
+        let scanned = time(&linear);
+        let lookups = (ROUNDS * probes.len()) as f64;
+        console_log!(
+            "route lookup over {} routes: indexed {:.1} ns, linear {:.1} ns",
+            DECODE_ROUTES.len(),
+            indexed * 1e6 / lookups,
+            scanned * 1e6 / lookups
+        );
+    }
 }
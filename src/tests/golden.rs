@@ -0,0 +1,1891 @@
+//! Golden layouts: one fixture per parser, each spelling out the on-chain
+//! byte layout field by field (independently of `fixtures.rs`) and pinning
+//! the complete decoded output. Every field carries a distinct value, with
+//! u64s past `u32::MAX` and u16s past `u8::MAX` where the layout allows, so a
+//! reordered, resized or skipped read changes the output instead of passing.
+//!
+//! Programs with an IDL in `idls/` build their fixtures through `from_idl`,
+//! which checks the field names, order and widths and the discriminator
+//! against the IDL, so those layouts don't rest on the parser's own reading.
+//! The others (Pump.fun, PumpSwap, Meteora DAMM, Raydium CPMM / AMM v4,
+//! Jupiter, SPL) are transcribed from the programs' published layouts.
+//!
+//! A transcription shares the parser's assumptions, so `captures.json` holds
+//! real mainnet instructions and accounts (with their signature or address)
+//! and the values an explorer decodes them to; `mainnet_captures` replays
+//! them through `decode`. Add entries with `scripts/capture.mjs`.
+
+use super::*;
+use serde_json::Value;
+
+const BOOP_IDL: &str = include_str!("../../idls/boop_idl.json");
+const LAUNCHLAB_IDL: &str = include_str!("../../idls/raydium_launchlab.mainnet.idl.json");
+const METEORA_IDL: &str = include_str!("../../idls/meteora_idl.json");
+const MOONSHOT_IDL: &str = include_str!("../../idls/moonshot_idl.json");
+const CAPTURES: &str = include_str!("captures.json");
+
+fn le(v: u64) -> [u8; 8] {
+    v.to_le_bytes()
+}
+
+fn le16(v: u16) -> [u8; 2] {
+    v.to_le_bytes()
+}
+
+fn pk(i: u8) -> [u8; 32] {
+    [i; 32]
+}
+
+/// Borsh string: u32 LE length, then the bytes.
+fn string(s: &str) -> Vec<u8> {
+    let mut out = Vec::new();
+    push_string(&mut out, s);
+    out
+}
+
+/// NUL-padded fixed-capacity string.
+fn fixed(s: &str, capacity: usize) -> Vec<u8> {
+    let mut out = s.as_bytes().to_vec();
+    out.resize(capacity, 0);
+    out
+}
+
+/// SPL token account prefix: mint, owner, amount.
+fn token_account(mint: u8, owner: u8, amount: u64) -> Vec<u8> {
+    [&pk(mint)[..], &pk(owner), &le(amount)].concat()
+}
+
+// ---- IDL layouts
+
+/// Definition of type `name`; older IDLs keep account structs under
+/// `accounts` rather than `types`.
+fn idl_type<'a>(idl: &'a Value, name: &str) -> &'a Value {
+    ["types", "accounts"]
+        .iter()
+        .filter_map(|section| idl[*section].as_array())
+        .flatten()
+        .find(|t| t["name"] == name)
+        .map(|t| &t["type"])
+        .unwrap_or_else(|| panic!("type {} not in IDL", name))
+}
+
+/// Name of a `defined` type: `{ "defined": "X" }` or `{ "defined": { "name": "X" } }`.
+fn defined(ty: &Value) -> Option<&str> {
+    let d = ty.get("defined")?;
+    d.as_str().or_else(|| d["name"].as_str())
+}
+
+/// Encoded size of `ty`, or `None` where strings, vecs, options or enums
+/// with data make it variable.
+fn idl_size(idl: &Value, ty: &Value) -> Option<usize> {
+    if let Some(name) = ty.as_str() {
+        return match name {
+            "u8" | "i8" | "bool" => Some(1),
+            "u16" | "i16" => Some(2),
+            "u32" | "i32" => Some(4),
+            "u64" | "i64" => Some(8),
+            "u128" | "i128" => Some(16),
+            "pubkey" | "publicKey" => Some(32),
+            _ => None,
+        };
+    }
+    if let Some([elem, len]) = ty.get("array").and_then(Value::as_array).map(Vec::as_slice) {
+        return Some(idl_size(idl, elem)? * len.as_u64()? as usize);
+    }
+    let def = idl_type(idl, defined(ty)?);
+    match def["kind"].as_str()? {
+        "struct" => def["fields"]
+            .as_array()?
+            .iter()
+            .map(|f| idl_size(idl, &f["type"]))
+            .sum(),
+        // A fieldless enum is its one-byte variant index
+        "enum" => def["variants"]
+            .as_array()?
+            .iter()
+            .all(|v| v.get("fields").is_none())
+            .then_some(1),
+        _ => None,
+    }
+}
+
+/// Leaf fields in declaration order, nested structs as `outer.inner`.
+fn idl_leaves(idl: &Value, fields: &[Value], prefix: &str, out: &mut Vec<(String, Value)>) {
+    for f in fields {
+        let name = format!("{}{}", prefix, f["name"].as_str().unwrap());
+        let ty = &f["type"];
+        let nested = defined(ty)
+            .map(|d| idl_type(idl, d))
+            .filter(|def| def["kind"] == "struct");
+        match nested {
+            Some(def) => {
+                let inner = def["fields"].as_array().unwrap();
+                idl_leaves(idl, inner, &format!("{}.", name), out);
+            }
+            None => out.push((name, ty.clone())),
+        }
+    }
+}
+
+/// Encode `fields` as IDL entry `name` of `section` ("instructions",
+/// "accounts" or "events"), prefixed with `discriminator`. The fields must
+/// be a leading run of the IDL's leaf fields, by name and in order, each
+/// with the IDL's width (strings by their length prefix); the IDL's own
+/// discriminator, where it records one, must equal `discriminator`.
+fn from_idl(
+    idl: &str,
+    section: &str,
+    name: &str,
+    discriminator: &[u8],
+    fields: &[(&str, &[u8])],
+) -> Vec<u8> {
+    let idl: Value = serde_json::from_str(idl).unwrap();
+    let entry = idl[section]
+        .as_array()
+        .and_then(|entries| entries.iter().find(|e| e["name"] == name))
+        .unwrap_or_else(|| panic!("{} not in IDL {}", name, section));
+    if let Some(d) = entry.get("discriminator") {
+        let d: Vec<u8> = serde_json::from_value(d.clone()).unwrap();
+        assert_eq!(d, discriminator, "discriminator of {}", name);
+    }
+
+    let layout = match section {
+        "instructions" => &entry["args"],
+        // Older IDLs inline event fields
+        _ if entry.get("fields").is_some() => &entry["fields"],
+        _ => &idl_type(&idl, name)["fields"],
+    };
+    let mut leaves = Vec::new();
+    idl_leaves(&idl, layout.as_array().unwrap(), "", &mut leaves);
+    assert!(
+        fields.len() <= leaves.len(),
+        "{} has only {} fields",
+        name,
+        leaves.len()
+    );
+
+    let mut out = discriminator.to_vec();
+    for ((field, bytes), (want, ty)) in fields.iter().zip(&leaves) {
+        assert_eq!(field, want, "field order of {}", name);
+        if ty == "string" {
+            let len = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+            assert_eq!(bytes.len(), 4 + len, "{}.{}", name, field);
+        } else if let Some(size) = idl_size(&idl, ty) {
+            assert_eq!(bytes.len(), size, "{}.{} width", name, field);
+        }
+        out.extend_from_slice(bytes);
+    }
+    out
+}
+
+// ---- rendering
+
+/// A decoded value as text: BigInts and numbers in decimal, arrays as
+/// `a,b`, objects as `{key=value,...}` in insertion order.
+fn render(v: &JsValue) -> String {
+    if let Some(b) = v.dyn_ref::<BigInt>() {
+        return b.to_string(10).unwrap().into();
+    }
+    if let Some(arr) = v.dyn_ref::<Array>() {
+        return arr.iter().map(|e| render(&e)).collect::<Vec<_>>().join(",");
+    }
+    if v.is_object() {
+        let fields = Object::entries(v.unchecked_ref())
+            .iter()
+            .map(|entry| {
+                let entry = Array::from(&entry);
+                format!(
+                    "{}={}",
+                    entry.get(0).as_string().unwrap(),
+                    render(&entry.get(1))
+                )
+            })
+            .collect::<Vec<_>>();
+        return format!("{{{}}}", fields.join(","));
+    }
+    v.as_string()
+        .or_else(|| v.as_bool().map(|b| b.to_string()))
+        .or_else(|| v.as_f64().map(|n| n.to_string()))
+        .unwrap_or_else(|| "null".into())
+}
+
+/// Assert that `out` has exactly the keys of `expected`, with those values.
+fn golden(out: &JsValue, expected: &[(&str, &dyn ToString)]) {
+    for (field, want) in expected {
+        assert_eq!(
+            render(&get(out, field)),
+            want.to_string(),
+            "field {}",
+            field
+        );
+    }
+    let keys = Object::keys(out.unchecked_ref());
+    assert_eq!(
+        keys.length() as usize,
+        expected.len(),
+        "unexpected keys in {}",
+        render(&keys)
+    );
+}
+
+// ---- pump_fun
+
+fn pump_create_event() -> Vec<u8> {
+    [
+        &D_PUMP_CREATE_EVENT[..],
+        &string("Golden Retriever"),
+        &string("GOLD"),
+        &string("https://ipfs.io/ipfs/QmGolden"),
+        &pk(1), // mint
+        &pk(2), // bonding_curve
+        &pk(3), // user
+    ]
+    .concat()
+}
+
+/// BondingCurve halfway to graduation, with the newer `creator` appended.
+fn pump_curve(with_creator: bool) -> Vec<u8> {
+    let mut out = [
+        &D_ACC_BONDING_CURVE[..],
+        &le(444_137_931_034_483),   // virtual_token_reserves
+        &le(72_500_000_000),        // virtual_sol_reserves
+        &le(164_237_931_034_483),   // real_token_reserves
+        &le(42_500_000_000),        // real_sol_reserves
+        &le(1_000_000_000_000_000), // token_total_supply
+        &[0],                       // complete
+    ]
+    .concat();
+    if with_creator {
+        out.extend_from_slice(&pk(0xcc));
+    }
+    out
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_create() {
+    let out = parse_pump_fun_create(&pump_create_event(), None, Some(true)).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"pump_fun"),
+            ("instruction", &"CreateEvent"),
+            ("name", &"Golden Retriever"),
+            ("symbol", &"GOLD"),
+            ("uri", &"https://ipfs.io/ipfs/QmGolden"),
+            ("mint", &key(1)),
+            ("pool", &key(2)),
+            ("developer", &key(3)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_create_full() {
+    let out = parse_pump_fun_create_full(&pump_create_event(), &pump_curve(false)).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"pump_fun"),
+            ("instruction", &"CreateEvent"),
+            ("name", &"Golden Retriever"),
+            ("symbol", &"GOLD"),
+            ("uri", &"https://ipfs.io/ipfs/QmGolden"),
+            ("mint", &key(1)),
+            ("pool", &key(2)),
+            ("developer", &key(3)),
+            ("virtual_token_reserves", &444_137_931_034_483u64),
+            ("virtual_sol_reserves", &72_500_000_000u64),
+            ("real_token_reserves", &164_237_931_034_483u64),
+            ("real_sol_reserves", &42_500_000_000u64),
+            ("token_total_supply", &1_000_000_000_000_000u64),
+            ("complete", &false),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_create_and_buy() {
+    let create = [
+        &D_PUMP_CREATE[..],
+        &string("Golden Retriever"),
+        &string("GOLD"),
+        &string("https://ipfs.io/ipfs/QmGolden"),
+        &pk(4), // creator
+    ]
+    .concat();
+    let buy = [&D_BUY[..], &le(35_000_000_000_000), &le(1_515_000_000)].concat();
+
+    let out = parse_pump_fun_create_and_buy(&create, Some(buy)).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"pump_fun"),
+            ("instruction", &"create"),
+            ("name", &"Golden Retriever"),
+            ("symbol", &"GOLD"),
+            ("uri", &"https://ipfs.io/ipfs/QmGolden"),
+            ("mint", &"null"),
+            ("pool", &"null"),
+            ("developer", &key(4)),
+            (
+                "initialBuy",
+                &"{tokenAmount=35000000000000,maxSolCost=1515000000}",
+            ),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_buy_with_fees() {
+    let data = [&D_BUY[..], &le(35_000_000_000_000), &le(2_000_000_000)].concat();
+    let out = parse_pump_fun_buy_with_fees(&data, 95, 5).unwrap();
+    golden(
+        &out,
+        &[
+            ("tokenAmount", &35_000_000_000_000u64),
+            ("maxSolCost", &2_000_000_000u64),
+            ("side", &"buy"),
+            ("amount", &35_000_000_000_000u64),
+            ("limitAmount", &2_000_000_000u64),
+            ("limitKind", &"max_in"),
+            ("maxProtocolFee", &18_811_882u64),
+            ("maxCreatorFee", &990_100u64),
+            ("maxNetSolAmount", &1_980_198_018u64),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_complete_event() {
+    let data = [
+        &D_PUMP_COMPLETE_EVENT[..],
+        &pk(1), // user
+        &pk(2), // mint
+        &pk(3), // bonding_curve
+        &1_717_171_717i64.to_le_bytes(),
+    ]
+    .concat();
+    let out = parse_pump_fun_complete_event(&data, Some(true)).unwrap();
+    golden(
+        &out,
+        &[
+            ("user", &key(1)),
+            ("mint", &key(2)),
+            ("bondingCurve", &key(3)),
+            ("timestamp", &1_717_171_717i64),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_trade_event() {
+    let data = [
+        &D_TRADE_EVENT[..],
+        &pk(1),                  // mint
+        &le(1_500_000_000),      // sol_amount
+        &le(35_000_000_000_000), // token_amount
+        &[1],                    // is_buy
+        &pk(2),                  // user
+        &1_717_171_718i64.to_le_bytes(),
+        &le(31_500_000_000),        // virtual_sol_reserves
+        &le(1_038_000_000_000_000), // virtual_token_reserves
+    ]
+    .concat();
+    let out = parse_pump_fun_trade_event(&data, None).unwrap();
+    golden(
+        &out,
+        &[
+            ("mint", &key(1)),
+            ("solAmount", &1_500_000_000u64),
+            ("solAmountUi", &"1.5"),
+            ("tokenAmount", &35_000_000_000_000u64),
+            ("isBuy", &true),
+            ("user", &key(2)),
+            ("timestamp", &1_717_171_718i64),
+            ("virtualSolReserves", &31_500_000_000u64),
+            ("virtualTokenReserves", &1_038_000_000_000_000u64),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_set_params() {
+    let data = [
+        &D_PUMP_SET_PARAMS[..],
+        &pk(1),                     // fee_recipient
+        &le(1_073_000_000_000_000), // initial_virtual_token_reserves
+        &le(30_000_000_000),        // initial_virtual_sol_reserves
+        &le(793_100_000_000_000),   // initial_real_token_reserves
+        &le(1_000_000_000_000_000), // token_total_supply
+        &le(100),                   // fee_basis_points
+    ]
+    .concat();
+    let out = parse_pump_fun_set_params(&data, Some(true)).unwrap();
+    golden(
+        &out,
+        &[
+            ("feeRecipient", &key(1)),
+            ("initialVirtualTokenReserves", &1_073_000_000_000_000u64),
+            ("initialVirtualSolReserves", &30_000_000_000u64),
+            ("initialRealTokenReserves", &793_100_000_000_000u64),
+            ("tokenTotalSupply", &1_000_000_000_000_000u64),
+            ("feeBasisPoints", &100u64),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_admin() {
+    let set_creator = [&D_PUMP_SET_CREATOR[..], &pk(0xcc)].concat();
+    let out = parse_pump_fun_admin(&set_creator, accounts(5), None).unwrap();
+    golden(
+        &out,
+        &[
+            ("instruction", &"set_creator"),
+            ("creator", &key(0xcc)),
+            ("authority", &key(0)),
+            ("mint", &key(2)),
+            ("bondingCurve", &key(4)),
+        ],
+    );
+
+    let admin_set_creator = [&D_PUMP_ADMIN_SET_CREATOR[..], &pk(0xcc)].concat();
+    let out = parse_pump_fun_admin(&admin_set_creator, accounts(4), None).unwrap();
+    golden(
+        &out,
+        &[
+            ("instruction", &"admin_set_creator"),
+            ("creator", &key(0xcc)),
+            ("authority", &key(0)),
+            ("mint", &key(2)),
+            ("bondingCurve", &key(3)),
+        ],
+    );
+
+    let out = parse_pump_fun_admin(&D_PUMP_EXTEND_ACCOUNT, accounts(2), None).unwrap();
+    golden(
+        &out,
+        &[
+            ("instruction", &"extend_account"),
+            ("account", &key(0)),
+            ("user", &key(1)),
+        ],
+    );
+
+    let out = parse_pump_fun_admin(&D_PUMP_UPDATE_GLOBAL_AUTHORITY, accounts(3), None).unwrap();
+    golden(
+        &out,
+        &[
+            ("instruction", &"update_global_authority"),
+            ("global", &key(0)),
+            ("authority", &key(1)),
+            ("newAuthority", &key(2)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_collect_creator_fee() {
+    let out = parse_pump_fun_collect_creator_fee(&D_PUMP_COLLECT_CREATOR_FEE, accounts(2)).unwrap();
+    golden(
+        &out,
+        &[
+            ("instruction", &"collect_creator_fee"),
+            ("creator", &key(0)),
+            ("creatorVault", &key(1)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_curve_state() {
+    let out = parse_pump_fun_curve_state(&pump_curve(true), Some(true), Some(true)).unwrap();
+    golden(
+        &out,
+        &[
+            ("virtual_token_reserves", &444_137_931_034_483u64),
+            ("virtual_sol_reserves", &72_500_000_000u64),
+            ("real_token_reserves", &164_237_931_034_483u64),
+            ("real_sol_reserves", &42_500_000_000u64),
+            ("token_total_supply", &1_000_000_000_000_000u64),
+            ("complete", &false),
+            // 42.5 of the default 85 SOL
+            ("progressPercent", &50),
+            ("remainderHex", &"cc".repeat(32)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_curve_state_with_creator() {
+    let out = parse_pump_fun_curve_state_with_creator(&pump_curve(true)).unwrap();
+    golden(
+        &out,
+        &[
+            ("virtual_token_reserves", &444_137_931_034_483u64),
+            ("virtual_sol_reserves", &72_500_000_000u64),
+            ("real_token_reserves", &164_237_931_034_483u64),
+            ("real_sol_reserves", &42_500_000_000u64),
+            ("token_total_supply", &1_000_000_000_000_000u64),
+            ("complete", &false),
+            ("creator", &key(0xcc)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_creator_info() {
+    let out = parse_pump_fun_creator_info(&pump_curve(true), accounts(10), None).unwrap();
+    golden(&out, &[("creator", &key(0xcc)), ("creatorVault", &key(9))]);
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_fun_global() {
+    let data = [
+        &D_ACC_PUMP_GLOBAL[..],
+        &[1],                       // initialized
+        &pk(1),                     // authority
+        &pk(2),                     // fee_recipient
+        &le(1_073_000_000_000_000), // initial_virtual_token_reserves
+        &le(30_000_000_000),        // initial_virtual_sol_reserves
+        &le(793_100_000_000_000),   // initial_real_token_reserves
+        &le(1_000_000_000_000_000), // token_total_supply
+        &le(95),                    // fee_basis_points
+        &pk(3),                     // withdraw_authority
+        &[1],                       // enable_migrate
+        &le(15_000_001),            // pool_migration_fee
+        &le(5),                     // creator_fee_basis_points
+    ]
+    .concat();
+    let out = parse_pump_fun_global(&data).unwrap();
+    golden(
+        &out,
+        &[
+            ("initialized", &true),
+            ("authority", &key(1)),
+            ("feeRecipient", &key(2)),
+            ("initialVirtualTokenReserves", &1_073_000_000_000_000u64),
+            ("initialVirtualSolReserves", &30_000_000_000u64),
+            ("initialRealTokenReserves", &793_100_000_000_000u64),
+            ("tokenTotalSupply", &1_000_000_000_000_000u64),
+            ("feeBasisPoints", &95),
+            ("creatorFeeBasisPoints", &5),
+        ],
+    );
+}
+
+// ---- raydium_launchpad
+
+/// PoolState halfway to its fundraising goal.
+fn launchpad_pool_state() -> Vec<u8> {
+    from_idl(
+        LAUNCHLAB_IDL,
+        "accounts",
+        "PoolState",
+        &D_ACC_POOL_STATE,
+        &[
+            ("epoch", &le(773)),
+            ("auth_bump", &[255]),
+            ("status", &[0]),
+            ("base_decimals", &[6]),
+            ("quote_decimals", &[9]),
+            ("migrate_type", &[1]),
+            ("supply", &le(1_000_000_000_000_000)),
+            ("total_base_sell", &le(793_100_000_000_000)),
+            ("virtual_base", &le(1_073_025_605_596_382)),
+            ("virtual_quote", &le(30_000_852_951)),
+            ("real_base", &le(200_000_000_000_000)),
+            ("real_quote", &le(42_500_000_000)),
+            ("total_quote_fund_raising", &le(85_000_000_000)),
+            ("quote_protocol_fee", &le(11_000_001)),
+            ("platform_fee", &le(22_000_002)),
+            ("migrate_fee", &le(33_000_003)),
+            ("vesting_schedule.total_locked_amount", &le(44_000_000_004)),
+            ("vesting_schedule.cliff_period", &le(55_005)),
+            ("vesting_schedule.unlock_period", &le(66_006)),
+            ("vesting_schedule.start_time", &le(1_717_000_007)),
+            (
+                "vesting_schedule.allocated_share_amount",
+                &le(77_000_000_008),
+            ),
+            ("global_config", &pk(1)),
+            ("platform_config", &pk(2)),
+            ("base_mint", &pk(3)),
+            ("quote_mint", &pk(4)),
+        ],
+    )
+}
+
+fn launchpad_global_config() -> Vec<u8> {
+    from_idl(
+        LAUNCHLAB_IDL,
+        "accounts",
+        "GlobalConfig",
+        &D_ACC_GLOBAL_CONFIG,
+        &[
+            ("epoch", &le(11)),
+            ("curve_type", &[1]),
+            ("index", &le16(258)),
+            ("migrate_fee", &le(15_000_000_001)),
+            ("trade_fee_rate", &le(2_500)),
+            ("max_share_fee_rate", &le(10_000)),
+            ("min_base_supply", &le(10_000_000)),
+            ("max_lock_rate", &le(300_000)),
+            ("min_base_sell_rate", &le(200_000)),
+            ("min_base_migrate_rate", &le(200_001)),
+            ("min_quote_fund_raising", &le(30_000_000_000)),
+            ("quote_mint", &pk(1)),
+            ("protocol_fee_owner", &pk(2)),
+            ("migrate_fee_owner", &pk(3)),
+            ("migrate_to_amm_wallet", &pk(4)),
+            ("migrate_to_cpswap_wallet", &pk(5)),
+        ],
+    )
+}
+
+/// `initialize*` args: 6-decimal mint, Constant curve, then `extra`
+/// (`amm_fee_on` and the Token-2022 transfer fee for the newer variants).
+fn launchpad_initialize(name: &str, discriminator: &[u8; 8], extra: &[(&str, &[u8])]) -> Vec<u8> {
+    // CurveParams::Constant { supply, total_base_sell, total_quote_fund_raising, migrate_type }
+    let curve = [
+        &[0][..],
+        &le(1_000_000_000_000_000),
+        &le(793_100_000_000_000),
+        &le(85_000_000_000),
+        &[1],
+    ]
+    .concat();
+    let fields: &[(&str, &[u8])] = &[
+        ("base_mint_param.decimals", &[6]),
+        ("base_mint_param.name", &string("Golden Bonk")),
+        ("base_mint_param.symbol", &string("GBONK")),
+        (
+            "base_mint_param.uri",
+            &string("https://golden.example/bonk.json"),
+        ),
+        ("curve_param", &curve),
+        ("vesting_param.total_locked_amount", &le(44_000_000_004)),
+        ("vesting_param.cliff_period", &le(55_005)),
+        ("vesting_param.unlock_period", &le(66_006)),
+    ];
+    from_idl(
+        LAUNCHLAB_IDL,
+        "instructions",
+        name,
+        discriminator,
+        &[fields, extra].concat(),
+    )
+}
+
+#[wasm_bindgen_test]
+fn golden_raydium_initialize() {
+    let data = launchpad_initialize("initialize", &D_RAY_INIT, &[]);
+    let out = parse_raydium_initialize(&data).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"raydium_launchpad"),
+            ("instruction", &"initialize"),
+            ("name", &"Golden Bonk"),
+            ("symbol", &"GBONK"),
+            ("uri", &"https://golden.example/bonk.json"),
+            ("mint", &"null"),
+            ("pool", &"null"),
+            ("developer", &"null"),
+        ],
+    );
+
+    // amm_fee_on = BothToken, then Some(transfer fee: 250 bps, max 1_000_000_001)
+    let transfer_fee = [&[1][..], &le16(250), &le(1_000_000_001)].concat();
+    let data = launchpad_initialize(
+        "initialize_with_token_2022",
+        &D_RAY_INIT_T22,
+        &[
+            ("amm_fee_on", &[1]),
+            ("transfer_fee_extension_param", &transfer_fee),
+        ],
+    );
+    let out = parse_raydium_initialize(&data).unwrap();
+    assert_eq!(str_of(&out, "instruction"), "initialize_with_token_2022");
+    assert_eq!(str_of(&out, "uri"), "https://golden.example/bonk.json");
+}
+
+#[wasm_bindgen_test]
+fn golden_believe_create() {
+    // amm_fee_on = QuoteToken
+    let data = launchpad_initialize("initialize_v2", &D_RAY_INIT_V2, &[("amm_fee_on", &[0])]);
+    let out = parse_believe_create(&data, accounts(7)).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"believe"),
+            ("instruction", &"initialize_v2"),
+            ("name", &"Golden Bonk"),
+            ("symbol", &"GBONK"),
+            ("uri", &"https://golden.example/bonk.json"),
+            ("mint", &key(6)),
+            ("pool", &key(5)),
+            ("developer", &key(1)),
+            ("platformConfig", &key(3)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_trades() {
+    let buy = from_idl(
+        LAUNCHLAB_IDL,
+        "instructions",
+        "buy_exact_in",
+        &D_RAY_BUY_EXACT_IN,
+        &[
+            ("amount_in", &le(1_000_000_001)),
+            ("minimum_amount_out", &le(35_000_000_000_002)),
+            ("share_fee_rate", &le(10_003)),
+        ],
+    );
+    let out = parse_raydium_launchpad_buy_exact_in(&buy, accounts(15), Some(true)).unwrap();
+    golden(
+        &out,
+        &[
+            ("amountIn", &1_000_000_001u64),
+            ("minimumAmountOut", &35_000_000_000_002u64),
+            ("shareFeeRate", &10_003u64),
+            ("shareFeeReceiver", &"null"),
+            ("payer", &key(0)),
+            ("platformConfig", &key(3)),
+            ("poolState", &key(4)),
+            ("baseMint", &key(9)),
+            ("quoteMint", &key(10)),
+            ("side", &"buy"),
+            ("amount", &1_000_000_001u64),
+            ("limitAmount", &35_000_000_000_002u64),
+            ("limitKind", &"min_out"),
+        ],
+    );
+
+    let sell = from_idl(
+        LAUNCHLAB_IDL,
+        "instructions",
+        "sell_exact_in",
+        &D_RAY_SELL_EXACT_IN,
+        &[
+            ("amount_in", &le(35_000_000_000_002)),
+            ("minimum_amount_out", &le(900_000_003)),
+            ("share_fee_rate", &le(0)),
+        ],
+    );
+    // A referred trade passes the share fee receiver as a 16th account
+    let out = parse_raydium_launchpad_sell_exact_in(&sell, accounts(16), Some(true)).unwrap();
+    golden(
+        &out,
+        &[
+            ("amountIn", &35_000_000_000_002u64),
+            ("minimumAmountOut", &900_000_003u64),
+            ("shareFeeRate", &0u64),
+            ("shareFeeReceiver", &key(15)),
+            ("payer", &key(0)),
+            ("platformConfig", &key(3)),
+            ("poolState", &key(4)),
+            ("baseMint", &key(9)),
+            ("quoteMint", &key(10)),
+            ("side", &"sell"),
+            ("amount", &35_000_000_000_002u64),
+            ("limitAmount", &900_000_003u64),
+            ("limitKind", &"min_out"),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_trade_event() {
+    let data = from_idl(
+        LAUNCHLAB_IDL,
+        "events",
+        "TradeEvent",
+        &D_TRADE_EVENT,
+        &[
+            ("pool_state", &pk(1)),
+            ("total_base_sell", &le(793_100_000_000_000)),
+            ("virtual_base", &le(1_073_025_605_596_382)),
+            ("virtual_quote", &le(30_000_852_951)),
+            ("real_base_before", &le(100_000_000_000_001)),
+            ("real_quote_before", &le(2_000_000_002)),
+            ("real_base_after", &le(110_000_000_000_003)),
+            ("real_quote_after", &le(2_300_000_004)),
+            ("amount_in", &le(300_000_005)),
+            ("amount_out", &le(10_000_000_000_006)),
+            ("protocol_fee", &le(750_007)),
+            ("platform_fee", &le(3_000_008)),
+            ("creator_fee", &le(9)),
+            ("share_fee", &le(10)),
+            ("trade_direction", &[0]), // buy
+            ("pool_status", &[2]),
+            ("exact_in", &[1]),
+        ],
+    );
+    let out = parse_launchpad_trade_event(&data).unwrap();
+    golden(
+        &out,
+        &[
+            ("poolState", &key(1)),
+            ("isBuy", &true),
+            ("exactIn", &true),
+            ("amountIn", &300_000_005u64),
+            ("amountOut", &10_000_000_000_006u64),
+            ("totalBaseSell", &793_100_000_000_000u64),
+            ("virtualBase", &1_073_025_605_596_382u64),
+            ("virtualQuote", &30_000_852_951u64),
+            ("realBaseBefore", &100_000_000_000_001u64),
+            ("realQuoteBefore", &2_000_000_002u64),
+            ("realBaseAfter", &110_000_000_000_003u64),
+            ("realQuoteAfter", &2_300_000_004u64),
+            ("protocolFee", &750_007u64),
+            ("platformFee", &3_000_008u64),
+            ("creatorFee", &9u64),
+            ("shareFee", &10u64),
+            ("poolStatus", &2),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_pool_state() {
+    let out = parse_launchpad_pool_state(&launchpad_pool_state(), None, None).unwrap();
+    golden(
+        &out,
+        &[
+            ("epoch", &773u64),
+            ("status", &0),
+            ("baseDecimals", &6),
+            ("quoteDecimals", &9),
+            ("migrateType", &1),
+            ("supply", &1_000_000_000_000_000u64),
+            ("totalBaseSell", &793_100_000_000_000u64),
+            ("virtualBase", &1_073_025_605_596_382u64),
+            ("virtualQuote", &30_000_852_951u64),
+            ("realBase", &200_000_000_000_000u64),
+            ("realQuote", &42_500_000_000u64),
+            ("totalQuoteFundRaising", &85_000_000_000u64),
+            (
+                "accumulators",
+                &"11000001,22000002,33000003,44000000004,55005,66006,1717000007,77000000008",
+            ),
+            ("globalConfig", &key(1)),
+            ("quoteMint", &key(4)),
+            ("isComplete", &false),
+            ("progressPercent", &50),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_global_config() {
+    let out = parse_launchpad_global_config(&launchpad_global_config()).unwrap();
+    golden(&out, &[("curveType", &1)]);
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_global_config_full() {
+    let out = parse_launchpad_global_config_full(&launchpad_global_config()).unwrap();
+    golden(
+        &out,
+        &[
+            ("epoch", &11u64),
+            ("curveType", &1),
+            ("index", &258),
+            ("migrateFee", &15_000_000_001u64),
+            ("tradeFeeRate", &2_500u64),
+            ("maxShareFeeRate", &10_000u64),
+            ("minBaseSupply", &10_000_000u64),
+            ("maxLockRate", &300_000u64),
+            ("minBaseSellRate", &200_000u64),
+            ("minBaseMigrateRate", &200_001u64),
+            ("minQuoteFundRaising", &30_000_000_000u64),
+            ("quoteMint", &key(1)),
+            ("feeOwner", &key(2)),
+            ("migrateFeeOwner", &key(3)),
+            ("migrateToAmmWallet", &key(4)),
+            ("migrateToCpswapWallet", &key(5)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_vesting() {
+    let record = from_idl(
+        LAUNCHLAB_IDL,
+        "accounts",
+        "VestingRecord",
+        &D_ACC_VESTING_RECORD,
+        &[
+            ("epoch", &le(773)),
+            ("pool", &pk(1)),
+            ("beneficiary", &pk(2)),
+            ("claimed_amount", &le(5_000_000_000_001)),
+            ("token_share_amount", &le(20_000_000_000_002)),
+        ],
+    );
+    let out = parse_launchpad_vesting(&record, &launchpad_pool_state()).unwrap();
+    golden(
+        &out,
+        &[
+            ("epoch", &773u64),
+            ("pool", &key(1)),
+            ("beneficiary", &key(2)),
+            ("claimedAmount", &5_000_000_000_001u64),
+            ("tokenShareAmount", &20_000_000_000_002u64),
+            ("totalLockedAmount", &44_000_000_004u64),
+            ("cliffPeriod", &55_005u64),
+            ("unlockPeriod", &66_006u64),
+            ("startTime", &1_717_000_007u64),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_platform_config() {
+    let data = from_idl(
+        LAUNCHLAB_IDL,
+        "accounts",
+        "PlatformConfig",
+        &D_ACC_PLATFORM_CONFIG,
+        &[
+            ("epoch", &le(12)),
+            ("platform_fee_wallet", &pk(1)),
+            ("platform_nft_wallet", &pk(2)),
+            ("platform_scale", &le(1_000_001)),
+            ("creator_scale", &le(2_000_002)),
+            ("burn_scale", &le(3_000_003)),
+            ("fee_rate", &le(10_000)),
+            ("name", &fixed("Golden Pad", 64)),
+            ("web", &fixed("https://golden.example", 256)),
+            ("img", &fixed("https://golden.example/logo.png", 256)),
+            ("cpswap_config", &pk(3)),
+            ("creator_fee_rate", &le(500)),
+        ],
+    );
+    let out = parse_launchpad_platform_config(&data).unwrap();
+    golden(
+        &out,
+        &[
+            ("epoch", &12u64),
+            ("platformFeeWallet", &key(1)),
+            ("platformNftWallet", &key(2)),
+            ("platformScale", &1_000_001u64),
+            ("creatorScale", &2_000_002u64),
+            ("burnScale", &3_000_003u64),
+            ("feeRate", &10_000u64),
+            ("name", &"Golden Pad"),
+            ("web", &"https://golden.example"),
+            ("img", &"https://golden.example/logo.png"),
+            ("cpswapConfig", &key(3)),
+            ("creatorFeeRate", &500u64),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_create_vesting() {
+    let data = from_idl(
+        LAUNCHLAB_IDL,
+        "instructions",
+        "create_vesting_account",
+        &D_RAY_CREATE_VESTING,
+        &[("share_amount", &le(20_000_000_000_002))],
+    );
+    let out = parse_launchpad_create_vesting(&data, accounts(4)).unwrap();
+    golden(
+        &out,
+        &[
+            ("shareAmount", &20_000_000_000_002u64),
+            ("creator", &key(0)),
+            ("beneficiary", &key(1)),
+            ("poolState", &key(2)),
+            ("vestingRecord", &key(3)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_claim_vested() {
+    let data = from_idl(
+        LAUNCHLAB_IDL,
+        "instructions",
+        "claim_vested_token",
+        &D_RAY_CLAIM_VESTED,
+        &[],
+    );
+    let out = parse_launchpad_claim_vested(&data, accounts(7)).unwrap();
+    golden(
+        &out,
+        &[
+            ("amount", &"null"),
+            ("beneficiary", &key(0)),
+            ("poolState", &key(2)),
+            ("vestingRecord", &key(3)),
+            ("userBaseToken", &key(5)),
+            ("baseMint", &key(6)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_launchpad_migrations() {
+    let data = from_idl(
+        LAUNCHLAB_IDL,
+        "instructions",
+        "migrate_to_amm",
+        &D_RAY_MIGRATE_TO_AMM,
+        &[
+            ("base_lot_size", &le(1_000_001)),
+            ("quote_lot_size", &le(2_000_002)),
+            ("market_vault_signer_nonce", &[253]),
+        ],
+    );
+    let out = parse_raydium_launchpad_migrate_to_amm(&data, accounts(24)).unwrap();
+    golden(
+        &out,
+        &[
+            ("destination", &"raydium_amm_v4"),
+            ("poolState", &key(23)),
+            ("baseMint", &key(1)),
+            ("quoteMint", &key(2)),
+            ("pool", &key(13)),
+            ("lpMint", &key(16)),
+            ("market", &key(4)),
+            ("baseLotSize", &1_000_001u64),
+            ("quoteLotSize", &2_000_002u64),
+            ("marketVaultSignerNonce", &253),
+        ],
+    );
+
+    let data = from_idl(
+        LAUNCHLAB_IDL,
+        "instructions",
+        "migrate_to_cpswap",
+        &D_RAY_MIGRATE_TO_CPSWAP,
+        &[],
+    );
+    let out = parse_raydium_launchpad_migrate_to_cpmm(&data, accounts(18)).unwrap();
+    golden(
+        &out,
+        &[
+            ("destination", &"raydium_cpmm"),
+            ("poolState", &key(17)),
+            ("baseMint", &key(1)),
+            ("quoteMint", &key(2)),
+            ("pool", &key(5)),
+            ("lpMint", &key(7)),
+            ("platformConfig", &key(3)),
+        ],
+    );
+}
+
+// ---- moonshot (the IDL predates recorded discriminators)
+
+fn moonshot_token_mint() -> Vec<u8> {
+    from_idl(
+        MOONSHOT_IDL,
+        "instructions",
+        "tokenMint",
+        &D_MOONSHOT_TOKEN_MINT,
+        &[
+            ("mintParams.name", &string("Golden Moon")),
+            ("mintParams.symbol", &string("GMOON")),
+            (
+                "mintParams.uri",
+                &string("https://golden.example/moon.json"),
+            ),
+            ("mintParams.decimals", &[9]),
+            ("mintParams.collateralCurrency", &[0]),
+            ("mintParams.amount", &le(1_000_000_000_000_000_000)),
+            ("mintParams.curveType", &[1]),
+            ("mintParams.migrationTarget", &[1]), // Meteora
+        ],
+    )
+}
+
+#[wasm_bindgen_test]
+fn golden_moonshot_token_mint() {
+    let out = parse_moonshot_token_mint(&moonshot_token_mint(), None).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"moonshot"),
+            ("instruction", &"token_mint"),
+            ("name", &"Golden Moon"),
+            ("symbol", &"GMOON"),
+            ("uri", &"https://golden.example/moon.json"),
+            ("mint", &"null"),
+            ("pool", &"null"),
+            ("developer", &"null"),
+            ("migrationTarget", &"Meteora"),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_moonshot_curve() {
+    let data = from_idl(
+        MOONSHOT_IDL,
+        "accounts",
+        "CurveAccount",
+        &D_ACC_MOONSHOT_CURVE,
+        &[
+            ("totalSupply", &le(1_000_000_000_000_000_001)),
+            ("curveAmount", &le(800_000_000_000_000_002)),
+            ("mint", &pk(1)),
+            ("decimals", &[9]),
+            ("collateralCurrency", &[0]),
+            ("curveType", &[1]),
+            ("marketcapThreshold", &le(345_000_000_003)),
+            ("marketcapCurrency", &[0]),
+            ("migrationFee", &le(5_000_000_004)),
+            ("coefB", &70_000u32.to_le_bytes()),
+            ("bump", &[253]),
+            ("migrationTarget", &[0]), // Raydium
+        ],
+    );
+    let out = parse_moonshot_curve(&data).unwrap();
+    golden(
+        &out,
+        &[
+            ("mint", &key(1)),
+            ("totalSupply", &1_000_000_000_000_000_001u64),
+            ("curveAmount", &800_000_000_000_000_002u64),
+            ("decimals", &9),
+            ("collateralCurrency", &0),
+            ("curveType", &"ConstantProductV1"),
+            ("marketcapThreshold", &345_000_000_003u64),
+            ("marketcapCurrency", &0),
+            ("migrationFee", &5_000_000_004u64),
+            ("coefB", &70_000),
+            ("bump", &253),
+            ("migrationTarget", &"Raydium"),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_token_name_symbol() {
+    let out = parse_token_name_symbol(PROGRAM_MOONSHOT, &moonshot_token_mint()).unwrap();
+    golden(
+        &out,
+        &[
+            ("name", &"Golden Moon"),
+            ("symbol", &"GMOON"),
+            ("uri", &"https://golden.example/moon.json"),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_moonshot_trades() {
+    let trade = |name: &str, discriminator: &[u8; 8], fixed_side: u8| {
+        from_idl(
+            MOONSHOT_IDL,
+            "instructions",
+            name,
+            discriminator,
+            &[
+                ("data.tokenAmount", &le(35_000_000_000_000_000)),
+                ("data.collateralAmount", &le(1_000_000_001)),
+                ("data.fixedSide", &[fixed_side]),
+                ("data.slippageBps", &le(500)),
+            ],
+        )
+    };
+
+    let out = parse_moonshot_buy(&trade("buy", &D_BUY, 1)).unwrap();
+    golden(
+        &out,
+        &[
+            ("tokenAmount", &35_000_000_000_000_000u64),
+            ("collateralAmount", &1_000_000_001u64),
+            ("fixedSide", &"ExactOut"),
+            ("slippageBps", &500u64),
+            ("side", &"buy"),
+            ("amount", &35_000_000_000_000_000u64),
+            ("limitAmount", &1_000_000_001u64),
+            ("limitKind", &"max_in"),
+        ],
+    );
+
+    let out = parse_moonshot_sell(&trade("sell", &D_SELL, 0)).unwrap();
+    golden(
+        &out,
+        &[
+            ("tokenAmount", &35_000_000_000_000_000u64),
+            ("collateralAmount", &1_000_000_001u64),
+            ("fixedSide", &"ExactIn"),
+            ("slippageBps", &500u64),
+            ("side", &"sell"),
+            ("amount", &35_000_000_000_000_000u64),
+            ("limitAmount", &1_000_000_001u64),
+            ("limitKind", &"min_out"),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_moonshot_migrate() {
+    let data = from_idl(
+        MOONSHOT_IDL,
+        "instructions",
+        "migrateFunds",
+        &D_MOONSHOT_MIGRATE_FUNDS,
+        &[],
+    );
+    let out = parse_moonshot_migrate(&data, accounts(6)).unwrap();
+    golden(
+        &out,
+        &[
+            ("mint", &key(5)),
+            ("curveAccount", &key(2)),
+            ("migrationAuthority", &key(1)),
+            ("migrationAuthorityTokenAccount", &key(4)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_moonshot_config() {
+    let data = from_idl(
+        MOONSHOT_IDL,
+        "accounts",
+        "ConfigAccount",
+        &D_ACC_MOONSHOT_CONFIG,
+        &[
+            ("migrationAuthority", &pk(1)),
+            ("backendAuthority", &pk(2)),
+            ("configAuthority", &pk(3)),
+            ("helioFee", &pk(4)),
+            ("dexFee", &pk(5)),
+            ("feeBps", &le16(300)),
+            ("dexFeeShare", &[60]),
+            ("migrationFee", &le(5_000_000_001)),
+            ("marketcapThreshold", &le(345_000_000_002)),
+            ("marketcapCurrency", &[0]),
+            ("minSupportedDecimalPlaces", &[6]),
+            ("maxSupportedDecimalPlaces", &[9]),
+            ("minSupportedTokenSupply", &le(10_000_003)),
+            ("maxSupportedTokenSupply", &le(1_000_000_000_000_000_004)),
+            ("bump", &[254]),
+            ("coefB", &70_000u32.to_le_bytes()),
+        ],
+    );
+    let out = parse_moonshot_config(&data).unwrap();
+    golden(
+        &out,
+        &[
+            ("migrationAuthority", &key(1)),
+            ("backendAuthority", &key(2)),
+            ("configAuthority", &key(3)),
+            ("helioFee", &key(4)),
+            ("dexFee", &key(5)),
+            ("feeBps", &300),
+            ("dexFeeShare", &60),
+            ("migrationFee", &5_000_000_001u64),
+            ("marketcapThreshold", &345_000_000_002u64),
+            ("marketcapCurrency", &0),
+            ("minSupportedDecimalPlaces", &6),
+            ("maxSupportedDecimalPlaces", &9),
+            ("minSupportedTokenSupply", &10_000_003u64),
+            ("maxSupportedTokenSupply", &1_000_000_000_000_000_004u64),
+            ("bump", &254),
+            ("coefB", &70_000),
+        ],
+    );
+}
+
+// ---- boop
+
+#[wasm_bindgen_test]
+fn golden_boop_create_token() {
+    let data = from_idl(
+        BOOP_IDL,
+        "instructions",
+        "create_token",
+        &D_BOOP_CREATE_TOKEN,
+        &[
+            ("salt", &le(42_000_000_001)),
+            ("name", &string("Golden Boop")),
+            ("symbol", &string("GBOOP")),
+            ("uri", &string("https://golden.example/boop.json")),
+        ],
+    );
+    let out = parse_boop_create_token(&data).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"boop"),
+            ("instruction", &"create_token"),
+            ("name", &"Golden Boop"),
+            ("symbol", &"GBOOP"),
+            ("uri", &"https://golden.example/boop.json"),
+            ("mint", &"null"),
+            ("pool", &"null"),
+            ("developer", &"null"),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_boop_trades() {
+    let buy = from_idl(
+        BOOP_IDL,
+        "instructions",
+        "buy_token",
+        &D_BOOP_BUY_TOKEN,
+        &[
+            ("buy_amount", &le(1_000_000_001)),
+            ("amount_out_min", &le(35_000_000_000_002)),
+        ],
+    );
+    let out = parse_boop_buy(&buy, accounts(7)).unwrap();
+    golden(
+        &out,
+        &[
+            ("amountIn", &1_000_000_001u64),
+            ("minimumAmountOut", &35_000_000_000_002u64),
+            ("mint", &key(0)),
+            ("bondingCurve", &key(1)),
+            ("user", &key(6)),
+            ("side", &"buy"),
+            ("amount", &1_000_000_001u64),
+            ("limitAmount", &35_000_000_000_002u64),
+            ("limitKind", &"min_out"),
+        ],
+    );
+
+    let sell = from_idl(
+        BOOP_IDL,
+        "instructions",
+        "sell_token",
+        &D_BOOP_SELL_TOKEN,
+        &[
+            ("sell_amount", &le(35_000_000_000_002)),
+            ("amount_out_min", &le(900_000_003)),
+        ],
+    );
+    let out = parse_boop_sell(&sell, accounts(7)).unwrap();
+    golden(
+        &out,
+        &[
+            ("amountIn", &35_000_000_000_002u64),
+            ("minimumAmountOut", &900_000_003u64),
+            ("mint", &key(0)),
+            ("bondingCurve", &key(1)),
+            ("user", &key(6)),
+            ("side", &"sell"),
+            ("amount", &35_000_000_000_002u64),
+            ("limitAmount", &900_000_003u64),
+            ("limitKind", &"min_out"),
+        ],
+    );
+}
+
+// ---- meteora_dbc
+
+fn meteora_initialize(name: &str, discriminator: &[u8; 8]) -> Vec<u8> {
+    from_idl(
+        METEORA_IDL,
+        "instructions",
+        name,
+        discriminator,
+        &[
+            ("params.name", &string("Golden Meteor")),
+            ("params.symbol", &string("GMET")),
+            ("params.uri", &string("https://golden.example/meteor.json")),
+        ],
+    )
+}
+
+#[wasm_bindgen_test]
+fn golden_meteora_initialize() {
+    let data = meteora_initialize(
+        "initialize_virtual_pool_with_spl_token",
+        &D_METEORA_INIT_SPL,
+    );
+    let out = parse_meteora_initialize(&data, accounts(6)).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"meteora_dbc"),
+            ("instruction", &"initialize_virtual_pool_with_spl_token"),
+            ("name", &"Golden Meteor"),
+            ("symbol", &"GMET"),
+            ("uri", &"https://golden.example/meteor.json"),
+            ("mint", &key(3)),
+            ("pool", &key(5)),
+            ("developer", &key(2)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_meteora_initialize_with_config() {
+    let data = meteora_initialize(
+        "initialize_virtual_pool_with_token2022",
+        &D_METEORA_INIT_T22,
+    );
+    let out = parse_meteora_initialize_with_config(&data, accounts(8)).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"meteora_dbc"),
+            ("instruction", &"initialize_virtual_pool_with_token2022"),
+            ("name", &"Golden Meteor"),
+            ("symbol", &"GMET"),
+            ("uri", &"https://golden.example/meteor.json"),
+            ("mint", &key(3)),
+            ("pool", &key(5)),
+            ("developer", &key(2)),
+            ("config", &key(0)),
+            ("quote_mint", &key(4)),
+            ("base_vault", &key(6)),
+            ("quote_vault", &key(7)),
+            ("token_type", &"token2022"),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_daos_fun_create() {
+    let data = meteora_initialize(
+        "initialize_virtual_pool_with_spl_token",
+        &D_METEORA_INIT_SPL,
+    );
+    let out = parse_daos_fun_create(&data, accounts(6)).unwrap();
+    golden(
+        &out,
+        &[
+            ("platform", &"daos_fun"),
+            ("instruction", &"initialize_virtual_pool_with_spl_token"),
+            ("name", &"Golden Meteor"),
+            ("symbol", &"GMET"),
+            ("uri", &"https://golden.example/meteor.json"),
+            ("mint", &key(3)),
+            ("pool", &key(5)),
+            ("developer", &key(2)),
+            ("dao_config", &key(0)),
+        ],
+    );
+}
+
+// ---- meteora_damm
+
+#[wasm_bindgen_test]
+fn golden_meteora_damm_pool_state() {
+    let data = [
+        &D_ACC_METEORA_DAMM_POOL[..],
+        &pk(1),             // lp_mint
+        &pk(2),             // token_a_mint
+        &pk(3),             // token_b_mint
+        &pk(4),             // a_vault
+        &pk(5),             // b_vault
+        &pk(6),             // a_vault_lp
+        &pk(7),             // b_vault_lp
+        &[255, 1],          // a_vault_lp_bump, enabled
+        &pk(8),             // protocol_token_a_fee
+        &pk(9),             // protocol_token_b_fee
+        &le(1_717_000_001), // fee_last_updated_at
+        &[0; 24],           // padding0
+        &le(250),           // trade_fee_numerator
+        &le(100_000),       // trade_fee_denominator
+        &le(20),            // protocol_trade_fee_numerator
+        &le(100),           // protocol_trade_fee_denominator
+        &[1],               // pool_type (Permissionless)
+    ]
+    .concat();
+    let a_lp = token_account(6, 1, 1_000_000_001);
+    let b_lp = token_account(7, 1, 2_000_000_002);
+    let out = parse_meteora_damm_pool_state(&data, &a_lp, &b_lp).unwrap();
+    golden(
+        &out,
+        &[
+            ("lpMint", &key(1)),
+            ("tokenAMint", &key(2)),
+            ("tokenBMint", &key(3)),
+            ("aVault", &key(4)),
+            ("bVault", &key(5)),
+            ("aVaultLp", &key(6)),
+            ("bVaultLp", &key(7)),
+            ("enabled", &true),
+            ("tradeFeeNumerator", &250u64),
+            ("tradeFeeDenominator", &100_000u64),
+            ("protocolTradeFeeNumerator", &20u64),
+            ("protocolTradeFeeDenominator", &100u64),
+            ("poolType", &1),
+            ("aVaultLpAmount", &1_000_000_001u64),
+            ("bVaultLpAmount", &2_000_000_002u64),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_meteora_damm_swap_event() {
+    let data = [
+        &D_METEORA_DAMM_SWAP_EVENT[..],
+        &le(1_000_000_001),  // in_amount
+        &le(35_000_000_002), // out_amount
+        &le(2_500_003),      // trade_fee
+        &le(500_004),        // protocol_fee
+        &le(5),              // host_fee
+    ]
+    .concat();
+    let out = parse_meteora_damm_swap_event(&data).unwrap();
+    golden(
+        &out,
+        &[
+            ("inAmount", &1_000_000_001u64),
+            ("outAmount", &35_000_000_002u64),
+            ("tradeFee", &2_500_003u64),
+            ("protocolFee", &500_004u64),
+            ("hostFee", &5u64),
+        ],
+    );
+}
+
+// ---- pump_swap
+
+#[wasm_bindgen_test]
+fn golden_pump_swap_liquidity() {
+    let liquidity = |discriminator: &[u8; 8]| {
+        [
+            &discriminator[..],
+            &le(1_000_000_001), // lp_token_amount
+            &le(2_000_000_002), // base bound
+            &le(3_000_000_003), // quote bound
+        ]
+        .concat()
+    };
+    let out = parse_pump_swap_deposit(&liquidity(&D_PUMP_SWAP_DEPOSIT), accounts(6)).unwrap();
+    golden(
+        &out,
+        &[
+            ("lpTokenAmount", &1_000_000_001u64),
+            ("maxBaseAmountIn", &2_000_000_002u64),
+            ("maxQuoteAmountIn", &3_000_000_003u64),
+            ("pool", &key(0)),
+            ("user", &key(2)),
+            ("baseMint", &key(3)),
+            ("quoteMint", &key(4)),
+            ("lpMint", &key(5)),
+        ],
+    );
+
+    let out = parse_pump_swap_withdraw(&liquidity(&D_PUMP_SWAP_WITHDRAW), accounts(6)).unwrap();
+    golden(
+        &out,
+        &[
+            ("lpTokenAmount", &1_000_000_001u64),
+            ("minBaseAmountOut", &2_000_000_002u64),
+            ("minQuoteAmountOut", &3_000_000_003u64),
+            ("pool", &key(0)),
+            ("user", &key(2)),
+            ("baseMint", &key(3)),
+            ("quoteMint", &key(4)),
+            ("lpMint", &key(5)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_swap_pool_state() {
+    let data = [
+        &D_ACC_PUMP_SWAP_POOL[..],
+        &[254],                 // pool_bump
+        &le16(258),             // index
+        &pk(1),                 // creator
+        &pk(2),                 // base_mint
+        &pk(3),                 // quote_mint
+        &pk(4),                 // lp_mint
+        &pk(5),                 // pool_base_token_account
+        &pk(6),                 // pool_quote_token_account
+        &le(4_193_388_000_001), // lp_supply
+        &pk(0xcc),              // coin_creator
+    ]
+    .concat();
+    let base_vault = token_account(2, 9, 206_900_000_000_002);
+    let quote_vault = token_account(3, 9, 84_990_359_003);
+    let out = parse_pump_swap_pool_state(&data, &base_vault, &quote_vault).unwrap();
+    golden(
+        &out,
+        &[
+            ("poolBump", &254),
+            ("index", &258),
+            ("creator", &key(1)),
+            ("baseMint", &key(2)),
+            ("quoteMint", &key(3)),
+            ("lpMint", &key(4)),
+            ("poolBaseTokenAccount", &key(5)),
+            ("poolQuoteTokenAccount", &key(6)),
+            ("lpSupply", &4_193_388_000_001u64),
+            ("coinCreator", &key(0xcc)),
+            ("baseReserve", &206_900_000_000_002u64),
+            ("quoteReserve", &84_990_359_003u64),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_pump_swap_create_pool() {
+    let data = [
+        &D_PUMP_SWAP_CREATE_POOL[..],
+        &le16(258),               // index
+        &le(206_900_000_000_001), // base_amount_in
+        &le(84_990_359_002),      // quote_amount_in
+        &pk(0xcc),                // coin_creator
+    ]
+    .concat();
+    let out = parse_pump_swap_create_pool(&data, accounts(6)).unwrap();
+    golden(
+        &out,
+        &[
+            ("index", &258),
+            ("baseAmountIn", &206_900_000_000_001u64),
+            ("quoteAmountIn", &84_990_359_002u64),
+            ("coinCreator", &key(0xcc)),
+            ("pool", &key(0)),
+            ("creator", &key(2)),
+            ("baseMint", &key(3)),
+            ("quoteMint", &key(4)),
+            ("lpMint", &key(5)),
+        ],
+    );
+}
+
+// ---- raydium_cpmm / raydium_amm_v4
+
+#[wasm_bindgen_test]
+fn golden_raydium_cpmm_initialize() {
+    let data = [
+        &D_RAY_INIT[..],
+        &le(793_100_000_000_001), // init_amount_0
+        &le(85_000_000_002),      // init_amount_1
+        &le(1_717_000_003),       // open_time
+    ]
+    .concat();
+    let out = parse_raydium_cpmm_initialize(&data, accounts(7)).unwrap();
+    golden(
+        &out,
+        &[
+            ("initAmount0", &793_100_000_000_001u64),
+            ("initAmount1", &85_000_000_002u64),
+            ("openTime", &1_717_000_003u64),
+            ("creator", &key(0)),
+            ("poolState", &key(3)),
+            ("token0Mint", &key(4)),
+            ("token1Mint", &key(5)),
+            ("lpMint", &key(6)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_raydium_cpmm_swaps() {
+    let swap = |discriminator: &[u8; 8]| {
+        [&discriminator[..], &le(1_000_000_001), &le(35_000_000_002)].concat()
+    };
+    type Parse = fn(&[u8], JsValue) -> Result<JsValue, JsValue>;
+    // (parser, discriminator, amount, limitAmount, limitKind)
+    let cases: [(Parse, [u8; 8], u64, u64, &str); 2] = [
+        (
+            parse_raydium_cpmm_swap_base_input,
+            D_CPMM_SWAP_BASE_INPUT,
+            1_000_000_001,
+            35_000_000_002,
+            "min_out",
+        ),
+        (
+            parse_raydium_cpmm_swap_base_output,
+            D_CPMM_SWAP_BASE_OUTPUT,
+            35_000_000_002,
+            1_000_000_001,
+            "max_in",
+        ),
+    ];
+    for (parse, discriminator, amount, limit_amount, limit_kind) in cases {
+        let out = parse(&swap(&discriminator), accounts(12)).unwrap();
+        golden(
+            &out,
+            &[
+                ("amountIn", &1_000_000_001u64),
+                ("amountOut", &35_000_000_002u64),
+                ("payer", &key(0)),
+                ("poolState", &key(3)),
+                ("inputMint", &key(10)),
+                ("outputMint", &key(11)),
+                ("side", &"sell"),
+                ("amount", &amount),
+                ("limitAmount", &limit_amount),
+                ("limitKind", &limit_kind),
+            ],
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn golden_raydium_amm_v4_initialize2() {
+    let data = [
+        &[TAG_AMM_V4_INITIALIZE2, 254][..], // tag, nonce
+        &le(1_717_000_001),                 // open_time
+        &le(79_000_000_002),                // init_pc_amount
+        &le(206_900_000_000_003),           // init_coin_amount
+    ]
+    .concat();
+    let out = parse_raydium_amm_v4_initialize2(&data, accounts(18)).unwrap();
+    golden(
+        &out,
+        &[
+            ("nonce", &254),
+            ("openTime", &1_717_000_001u64),
+            ("initPcAmount", &79_000_000_002u64),
+            ("initCoinAmount", &206_900_000_000_003u64),
+            ("ammId", &key(4)),
+            ("lpMint", &key(7)),
+            ("coinMint", &key(8)),
+            ("pcMint", &key(9)),
+            ("creator", &key(17)),
+        ],
+    );
+}
+
+// ---- jupiter
+
+#[wasm_bindgen_test]
+fn golden_jupiter_route() {
+    // Opaque route plan (vec of one 3-byte step), then the fixed tail
+    let route = |discriminator: &[u8; 8]| {
+        [
+            &discriminator[..],
+            &1u32.to_le_bytes(),
+            &[7, 0, 1],
+            &le(1_000_000_001),  // in_amount / out_amount
+            &le(35_000_000_002), // quoted_out_amount / quoted_in_amount
+            &le16(300),          // slippage_bps
+            &[20],               // platform_fee_bps
+        ]
+        .concat()
+    };
+
+    let out = parse_jupiter_route(&route(&D_JUP_ROUTE)).unwrap();
+    golden(
+        &out,
+        &[
+            ("instruction", &"route"),
+            ("inAmount", &1_000_000_001u64),
+            ("quotedOutAmount", &35_000_000_002u64),
+            ("slippageBps", &300),
+            ("platformFeeBps", &20),
+        ],
+    );
+
+    let out = parse_jupiter_route(&route(&D_JUP_EXACT_OUT_ROUTE)).unwrap();
+    golden(
+        &out,
+        &[
+            ("instruction", &"exact_out_route"),
+            ("outAmount", &1_000_000_001u64),
+            ("quotedInAmount", &35_000_000_002u64),
+            ("slippageBps", &300),
+            ("platformFeeBps", &20),
+        ],
+    );
+}
+
+// ---- spl
+
+#[wasm_bindgen_test]
+fn golden_spl_memo() {
+    let out = parse_spl_memo(b"golden memo").unwrap();
+    golden(&out, &[("memo", &"golden memo")]);
+}
+
+#[wasm_bindgen_test]
+fn golden_create_associated_token_account() {
+    let out = parse_create_associated_token_account(accounts(6)).unwrap();
+    golden(
+        &out,
+        &[
+            ("funder", &key(0)),
+            ("ata", &key(1)),
+            ("owner", &key(2)),
+            ("mint", &key(3)),
+        ],
+    );
+}
+
+#[wasm_bindgen_test]
+fn golden_token_2022_transfer_checked() {
+    let plain = [&[TAG_TOKEN_TRANSFER_CHECKED][..], &le(1_500_000_001), &[6]].concat();
+    let out = parse_token_2022_transfer_checked(&plain).unwrap();
+    golden(
+        &out,
+        &[
+            ("amount", &1_500_000_001u64),
+            ("decimals", &6),
+            ("fee", &"null"),
+        ],
+    );
+
+    let with_fee = [
+        &[
+            TAG_TOKEN_TRANSFER_FEE_EXTENSION,
+            TAG_TRANSFER_CHECKED_WITH_FEE,
+        ][..],
+        &le(1_500_000_001), // amount
+        &[9],               // decimals
+        &le(15_000_002),    // fee
+    ]
+    .concat();
+    let out = parse_token_2022_transfer_checked(&with_fee).unwrap();
+    golden(
+        &out,
+        &[
+            ("amount", &1_500_000_001u64),
+            ("decimals", &9),
+            ("fee", &15_000_002u64),
+        ],
+    );
+}
+
+// ---- mainnet captures
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Each capture decodes to `{ platform, instruction, fields }` whose
+/// `expected` keys render to the recorded strings.
+#[wasm_bindgen_test]
+fn mainnet_captures() {
+    let captures: Vec<Value> = serde_json::from_str(CAPTURES).unwrap();
+    for capture in &captures {
+        let source = capture["source"].as_str().unwrap();
+        let accounts = Array::new();
+        for key in capture["accounts"].as_array().unwrap() {
+            accounts.push(&JsValue::from_str(key.as_str().unwrap()));
+        }
+        let out = decode(
+            capture["programId"].as_str().unwrap(),
+            &from_hex(capture["dataHex"].as_str().unwrap()),
+            JsValue::from(accounts),
+            None,
+        )
+        .unwrap_or_else(|e| panic!("{}: {:?}", source, e));
+        let fields = get(&out, "fields");
+        for (key, want) in capture["expected"].as_object().unwrap() {
+            let got = match key.as_str() {
+                "platform" | "instruction" => render(&get(&out, key)),
+                _ => render(&get(&fields, key)),
+            };
+            assert_eq!(&got, want.as_str().unwrap(), "{} field {}", source, key);
+        }
+    }
+}